@@ -4,11 +4,13 @@ use crate::commands::WholeStreamCommand;
 use crate::context::CommandRegistry;
 use crate::prelude::*;
 use deduction_to_signature::DeductionToSignature;
+use indexmap::IndexMap;
 use log::trace;
 use nu_data::config;
 use nu_errors::ShellError;
 use nu_protocol::{
-    hir::Block, CommandAction, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
+    hir::Block, CommandAction, NamedType, ReturnSuccess, Signature, SyntaxShape, UntaggedValue,
+    Value,
 };
 use nu_source::Tagged;
 
@@ -76,6 +78,16 @@ impl WholeStreamCommand for Alias {
                 example: "alias l [first, x...] { ls $first $x }",
                 result: None,
             },
+            Example {
+                description: "An alias with an optional parameter and a default value",
+                example: "alias greet [name=\"world\"] { echo $\"hello ($name)\" }",
+                result: None,
+            },
+            Example {
+                description: "An alias with a named flag",
+                example: "alias ll [--all] { ls --all $all }",
+                result: None,
+            },
         ]
     }
 }
@@ -90,12 +102,34 @@ pub fn is_var_arg(var_name: &str) -> bool {
     var_name.ends_with("...")
 }
 
+/// Splits a declared alias parameter or flag name on a trailing `=default`,
+/// so an alias can opt a positional or a named flag into taking a default
+/// the same way: `alias greet [name="world"] { echo $name }` or
+/// `alias ls-it [--long=false] { ls --long $long }`.
+fn split_default(raw: &str) -> (String, Option<String>) {
+    match raw.split_once('=') {
+        Some((name, default)) => (name.to_string(), Some(default.to_string())),
+        None => (raw.to_string(), None),
+    }
+}
+
+/// Pulls the alias name back out of a persisted `alias NAME [args] { ... }`
+/// startup command, so an existing entry can be matched by the name it
+/// actually defines instead of by a brittle prefix comparison against the
+/// about-to-be-saved command text.
+fn alias_name_in_command(command: &str) -> Option<&str> {
+    let mut words = command.split_whitespace();
+    if words.next()? != "alias" {
+        return None;
+    }
+    words.next()
+}
+
 pub async fn alias(
     args: CommandArgs,
     registry: &CommandRegistry,
 ) -> Result<OutputStream, ShellError> {
     let registry = registry.clone();
-    let mut raw_input = args.raw_input.clone();
     let (
         AliasArgs {
             name,
@@ -110,32 +144,39 @@ pub async fn alias(
     if let Some(true) = save {
         let mut result = nu_data::config::read(name.clone().tag, &None)?;
 
-        // process the alias to remove the --save flag
-        let left_brace = raw_input.find('{').unwrap_or(0);
-        let right_brace = raw_input.rfind('}').unwrap_or_else(|| raw_input.len());
-        let left = raw_input[..left_brace]
-            .replace("--save", "") // TODO using regex (or reconstruct string from AST?)
-            .replace("-si", "-i")
-            .replace("-s ", "")
-            .replace("-is", "-i");
-        let right = raw_input[right_brace..]
-            .replace("--save", "")
-            .replace("-si", "-i")
-            .replace("-s ", "")
-            .replace("-is", "-i");
-        raw_input = format!("{}{}{}", left, &raw_input[left_brace..right_brace], right);
-
-        // create a value from raw_input alias
-        let alias: Value = raw_input.trim().to_string().into();
-        let alias_start = raw_input.find('[').unwrap_or(0); // used to check if the same alias already exists
+        // Rebuild the command to persist from the parsed pieces rather than
+        // patching the raw input text: the raw text still has `--save` (and
+        // its short-flag variants) in it, and string surgery on it is fooled
+        // by an alias body that happens to contain those same substrings.
+        let args_str = list
+            .iter()
+            .map(|value| {
+                value.as_string().map_err(|_| {
+                    ShellError::labeled_error("Expected a string", "expected a string", value.tag())
+                })
+            })
+            .collect::<Result<Vec<_>, ShellError>>()?
+            .join(", ");
+        let command_str = if let Some(true) = _infer {
+            format!("alias {} [{}] {} --infer", name.item, args_str, block)
+        } else {
+            format!("alias {} [{}] {}", name.item, args_str, block)
+        };
+
+        // create a value from the reconstructed alias
+        let alias: Value = command_str.into();
 
         // add to startup if alias doesn't exist and replace if it does
         match result.get_mut("startup") {
             Some(startup) => {
                 if let UntaggedValue::Table(ref mut commands) = startup.value {
                     if let Some(command) = commands.iter_mut().find(|command| {
-                        let cmd_str = command.as_string().unwrap_or_default();
-                        cmd_str.starts_with(&raw_input[..alias_start])
+                        command
+                            .as_string()
+                            .ok()
+                            .as_deref()
+                            .and_then(alias_name_in_command)
+                            == Some(name.item.as_str())
                     }) {
                         *command = alias;
                     } else {
@@ -152,11 +193,19 @@ pub async fn alias(
     }
 
     let mut processed_args: Vec<VarDeclaration> = vec![];
+    let mut positional_defaults: IndexMap<String, Option<String>> = IndexMap::new();
+    let mut flags: IndexMap<String, Option<String>> = IndexMap::new();
     for (idx, item) in list.iter().enumerate() {
         match item.as_string() {
-            Ok(var_name) => {
+            Ok(raw_name) => {
+                if let Some(flag_name) = raw_name.strip_prefix("--") {
+                    let (flag_name, default) = split_default(flag_name);
+                    flags.insert(flag_name, default);
+                    continue;
+                }
+
                 let (dollar_var_name, is_var_arg) = {
-                    if is_var_arg(&var_name) {
+                    if is_var_arg(&raw_name) {
                         //Var args are only allowed in last place
                         if (idx + 1) != list.len() {
                             return Err(ShellError::labeled_error(
@@ -165,8 +214,24 @@ pub async fn alias(
                                 item.tag.span,
                             ));
                         }
-                        (format!("${}", var_arg_name(&var_name)), true)
+                        (format!("${}", var_arg_name(&raw_name)), true)
                     } else {
+                        let (var_name, default) = split_default(&raw_name);
+                        let var_name = match var_name.strip_suffix('?') {
+                            Some(var_name) => {
+                                positional_defaults
+                                    .entry(format!("${}", var_name))
+                                    .or_insert(None);
+                                var_name.to_string()
+                            }
+                            None => {
+                                if default.is_some() {
+                                    positional_defaults
+                                        .insert(format!("${}", var_name), default);
+                                }
+                                var_name
+                            }
+                        };
                         (format!("${}", var_name), false)
                     }
                 };
@@ -189,7 +254,16 @@ pub async fn alias(
     trace!("Found vars: {:?}", processed_args);
 
     let inferred_shapes = VarSyntaxShapeDeductor::infer_vars(&processed_args, &block, &registry)?;
-    let signature = DeductionToSignature::get(&name.item, &inferred_shapes);
+    let mut signature =
+        DeductionToSignature::get(&name.item, &inferred_shapes, &positional_defaults)?;
+
+    for (flag_name, default) in flags {
+        let named_type = match default {
+            Some(default) => NamedType::Optional(None, SyntaxShape::Any, Some(default)),
+            None => NamedType::Switch(None),
+        };
+        signature.named.insert(flag_name, (named_type, "".to_string()));
+    }
 
     Ok(OutputStream::one(ReturnSuccess::action(
         CommandAction::AddAlias(signature, block),
@@ -211,6 +285,8 @@ mod tests {
 //TODO better naming
 mod deduction_to_signature {
     use crate::commands::deduction::{VarDeclaration, VarShapeDeduction};
+    use indexmap::IndexMap;
+    use nu_errors::ShellError;
     use nu_protocol::{PositionalType, Signature, SyntaxShape};
     use nu_source::Span;
 
@@ -219,7 +295,8 @@ mod deduction_to_signature {
         pub fn get(
             cmd_name: &str,
             deductions: &[(VarDeclaration, Option<Vec<VarShapeDeduction>>)],
-        ) -> Signature {
+            positional_defaults: &IndexMap<String, Option<String>>,
+        ) -> Result<Signature, ShellError> {
             let deductions: Vec<(VarDeclaration, VarShapeDeduction)> = deductions
                 .iter()
                 .map(|(decl, deducs)| {
@@ -230,39 +307,22 @@ mod deduction_to_signature {
                     };
                     let decl = decl.clone();
                     match deducs {
-                        Some(deduc) => {
-                            //Pick more general shapes over other shapes
-
-                            //Pick any over anything
-                            if let Some(any_shape) = deduc
-                                .iter()
-                                .find(|deduc| deduc.deduction == SyntaxShape::Any)
-                            {
-                                (decl, any_shape.clone())
-                            }
-                            //Pick math over other shapes
-                            else if let Some(math_shape) = deduc
-                                .iter()
-                                .find(|deduc| deduc.deduction == SyntaxShape::Math)
-                            {
-                                (decl, math_shape.clone())
-                            } else {
-                                //Pick first shape
-                                (decl, deduc[0].clone())
-                            }
-                        }
-                        None => (decl, default),
+                        Some(deduc) => Ok((decl, Self::pick_deduction(&decl, deduc)?)),
+                        None => Ok((decl, default)),
                     }
                 })
-                .collect();
+                .collect::<Result<_, ShellError>>()?;
 
             let mut sig = Signature::build(cmd_name);
             for (var_decl, shape) in &deductions {
                 //TODO pass in better description
-                sig.positional.push((
-                    PositionalType::mandatory(&var_decl.name, shape.deduction),
-                    "".to_string(),
-                ));
+                let pos_type = match positional_defaults.get(&var_decl.name) {
+                    Some(default) => {
+                        PositionalType::optional(&var_decl.name, shape.deduction, default.clone())
+                    }
+                    None => PositionalType::mandatory(&var_decl.name, shape.deduction),
+                };
+                sig.positional.push((pos_type, "".to_string()));
             }
             if let Some(last_arg) = deductions.last() {
                 if last_arg.0.is_var_arg {
@@ -271,7 +331,68 @@ mod deduction_to_signature {
                 }
             }
 
-            sig
+            Ok(sig)
+        }
+
+        /// Picks the shape a variable's positional argument should take out of
+        /// every deduction collected for it. A variable used only inside a math
+        /// expression is deduced as `Math`, and a variable never pinned down by
+        /// a concrete usage defaults to `Any` — those are the *absence* of a
+        /// concrete shape, not a competing one, so they must lose to any
+        /// concrete deduction rather than winning by appearing first. Two
+        /// distinct concrete deductions for the same variable are a genuine
+        /// conflict (e.g. `$x` passed to both a command expecting `String` and
+        /// one expecting `Int`) and are reported with both offending spans
+        /// rather than silently picking one.
+        fn pick_deduction(
+            decl: &VarDeclaration,
+            deducs: &[VarShapeDeduction],
+        ) -> Result<VarShapeDeduction, ShellError> {
+            let mut concrete = deducs
+                .iter()
+                .filter(|deduc| deduc.deduction != SyntaxShape::Any && deduc.deduction != SyntaxShape::Math);
+
+            let first_concrete = concrete.next();
+            if let Some(conflicting) = concrete.find(|deduc| Some(deduc.deduction) != first_concrete.map(|d| d.deduction)) {
+                let first_concrete = first_concrete.expect("just matched Some above");
+                return Err(ShellError::labeled_error_with_secondary(
+                    format!(
+                        "Conflicting types deduced for ${}: {:?} and {:?}",
+                        decl.name, first_concrete.deduction, conflicting.deduction
+                    ),
+                    format!("deduced as {:?} here", first_concrete.deduction),
+                    *first_concrete
+                        .deducted_from
+                        .first()
+                        .unwrap_or(&Span::unknown()),
+                    format!("but deduced as {:?} here", conflicting.deduction),
+                    *conflicting
+                        .deducted_from
+                        .first()
+                        .unwrap_or(&Span::unknown()),
+                ));
+            }
+
+            if let Some(concrete_shape) = first_concrete {
+                return Ok(concrete_shape.clone());
+            }
+
+            if let Some(math_shape) = deducs
+                .iter()
+                .find(|deduc| deduc.deduction == SyntaxShape::Math)
+            {
+                return Ok(math_shape.clone());
+            }
+
+            Ok(deducs
+                .iter()
+                .find(|deduc| deduc.deduction == SyntaxShape::Any)
+                .cloned()
+                .unwrap_or_else(|| VarShapeDeduction {
+                    deduction: SyntaxShape::Any,
+                    deducted_from: vec![Span::unknown()],
+                    many_of_shapes: false,
+                }))
         }
     }
 }
@@ -5,11 +5,13 @@ use crate::evaluate::evaluate_args::evaluate_args;
 use crate::prelude::*;
 use derive_new::new;
 use getset::Getters;
+use nu_engine::FromValue;
 use nu_errors::ShellError;
 use nu_protocol::hir;
 use nu_protocol::{CallInfo, EvaluatedArgs, ReturnValue, Scope, Signature, Value};
 use serde::{Deserialize, Serialize};
 use std::ops::Deref;
+use std::cell::RefCell;
 use std::sync::atomic::AtomicBool;
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -20,8 +22,19 @@ pub struct UnevaluatedCallInfo {
 }
 
 impl UnevaluatedCallInfo {
-    pub fn evaluate(self, registry: &CommandRegistry) -> Result<CallInfo, ShellError> {
-        let args = evaluate_args(&self.args, registry, &self.scope)?;
+    pub async fn evaluate(self, registry: &CommandRegistry) -> Result<CallInfo, ShellError> {
+        let tracing = self.switch_present("trace");
+        if tracing {
+            trace_enter(self.args.clone(), None);
+        }
+
+        let result = evaluate_args(&self.args, registry, &self.scope).await;
+
+        if tracing {
+            trace_exit(trace_summary(&result));
+        }
+
+        let args = result?;
 
         Ok(CallInfo {
             args,
@@ -29,14 +42,25 @@ impl UnevaluatedCallInfo {
         })
     }
 
-    pub fn evaluate_with_new_it(
+    pub async fn evaluate_with_new_it(
         self,
         registry: &CommandRegistry,
         it: &Value,
     ) -> Result<CallInfo, ShellError> {
+        let tracing = self.switch_present("trace");
+        if tracing {
+            trace_enter(self.args.clone(), Some(it.clone()));
+        }
+
         let mut scope = self.scope.clone();
         scope = scope.set_it(it.clone());
-        let args = evaluate_args(&self.args, registry, &scope)?;
+        let result = evaluate_args(&self.args, registry, &scope).await;
+
+        if tracing {
+            trace_exit(trace_summary(&result));
+        }
+
+        let args = result?;
 
         Ok(CallInfo {
             args,
@@ -49,36 +73,91 @@ impl UnevaluatedCallInfo {
     }
 }
 
-pub trait CallInfoExt {
-    fn process<'de, T: Deserialize<'de>>(
-        &self,
-        shell_manager: &ShellManager,
-        ctrl_c: Arc<AtomicBool>,
-        callback: fn(T, &RunnablePerItemContext) -> Result<OutputStream, ShellError>,
-    ) -> Result<RunnablePerItemArgs<T>, ShellError>;
+fn trace_summary(result: &Result<EvaluatedArgs, ShellError>) -> String {
+    match result {
+        Ok(args) => format!("{:?}", args),
+        Err(err) => format!("error: {:?}", err),
+    }
 }
 
-impl CallInfoExt for CallInfo {
-    fn process<'de, T: Deserialize<'de>>(
-        &self,
-        shell_manager: &ShellManager,
-        ctrl_c: Arc<AtomicBool>,
-        callback: fn(T, &RunnablePerItemContext) -> Result<OutputStream, ShellError>,
-    ) -> Result<RunnablePerItemArgs<T>, ShellError> {
-        let mut deserializer = ConfigDeserializer::from_call_info(self.clone());
+/// One recorded argument expansion: the unevaluated call fragment, the `$it`
+/// it was resolved against (if any, e.g. a per-row `evaluate_with_new_it`),
+/// and a summary of the produced `EvaluatedArgs`/`ShellError`. Nodes are
+/// pushed on `trace_enter` and popped on `trace_exit`, mirroring the
+/// `enter_scope`/`exit_scope` pairing `keep until` already uses for nested
+/// block evaluation, so a block condition's own argument expansion shows up
+/// nested under the command that evaluated it.
+pub struct TraceNode {
+    call: hir::Call,
+    it: Option<Value>,
+    summary: String,
+    children: Vec<TraceNode>,
+}
 
-        Ok(RunnablePerItemArgs {
-            args: T::deserialize(&mut deserializer)?,
-            context: RunnablePerItemContext {
-                shell_manager: shell_manager.clone(),
-                name: self.name_tag.clone(),
-                ctrl_c,
-            },
-            callback,
-        })
+impl PrettyDebugWithSource for TraceNode {
+    fn pretty_debug(&self, source: &str) -> DebugDocBuilder {
+        let detail = match &self.it {
+            Some(it) => format!("$it = {} -> {}", it.display(), self.summary),
+            None => self.summary.clone(),
+        };
+
+        let mut doc = b::typed(
+            "trace",
+            self.call.pretty_debug(source)
+                + b::space()
+                + b::equals()
+                + b::space()
+                + b::description(detail),
+        );
+
+        for child in &self.children {
+            doc = doc + b::space() + child.pretty_debug(source);
+        }
+
+        doc
+    }
+}
+
+thread_local! {
+    static TRACE_STACK: RefCell<Vec<TraceNode>> = RefCell::new(Vec::new());
+    static TRACE_ROOTS: RefCell<Vec<TraceNode>> = RefCell::new(Vec::new());
+}
+
+fn trace_enter(call: hir::Call, it: Option<Value>) {
+    TRACE_STACK.with(|stack| {
+        stack.borrow_mut().push(TraceNode {
+            call,
+            it,
+            summary: String::new(),
+            children: Vec::new(),
+        });
+    });
+}
+
+fn trace_exit(summary: String) {
+    let node = TRACE_STACK.with(|stack| stack.borrow_mut().pop());
+
+    if let Some(mut node) = node {
+        node.summary = summary;
+
+        TRACE_STACK.with(|stack| match stack.borrow_mut().last_mut() {
+            Some(parent) => parent.children.push(node),
+            None => TRACE_ROOTS.with(|roots| roots.borrow_mut().push(node)),
+        });
     }
 }
 
+/// Prints and clears whatever trace nodes have accumulated for the current
+/// thread since the last call. Meant to run once a `--trace`d pipeline has
+/// finished, from `Command::run`.
+pub fn print_trace() {
+    TRACE_ROOTS.with(|roots| {
+        for root in roots.borrow_mut().drain(..) {
+            println!("{:?}", root.pretty_debug(""));
+        }
+    });
+}
+
 #[derive(Getters)]
 #[get = "pub(crate)"]
 pub struct CommandArgs {
@@ -87,27 +166,13 @@ pub struct CommandArgs {
     pub shell_manager: ShellManager,
     pub call_info: UnevaluatedCallInfo,
     pub input: InputStream,
-}
-
-#[derive(Getters, Clone)]
-#[get = "pub(crate)"]
-pub struct RawCommandArgs {
-    pub host: Arc<parking_lot::Mutex<Box<dyn Host>>>,
-    pub ctrl_c: Arc<AtomicBool>,
-    pub shell_manager: ShellManager,
-    pub call_info: UnevaluatedCallInfo,
-}
-
-impl RawCommandArgs {
-    pub fn with_input(self, input: impl Into<InputStream>) -> CommandArgs {
-        CommandArgs {
-            host: self.host,
-            ctrl_c: self.ctrl_c,
-            shell_manager: self.shell_manager,
-            call_info: self.call_info,
-            input: input.into(),
-        }
-    }
+    /// Recoverable per-row errors recorded by `process_each` instead of
+    /// aborting the whole stream at the first bad row. Shared with
+    /// `RunnableContext`/`ItemContext` so a filter command can keep going
+    /// after one row fails and report the failures once the pipeline ends.
+    /// `FnFilterCommand` does not record into it: its per-row errors only
+    /// appear in its output stream.
+    pub current_errors: Arc<parking_lot::Mutex<Vec<ShellError>>>,
 }
 
 impl std::fmt::Debug for CommandArgs {
@@ -117,7 +182,7 @@ impl std::fmt::Debug for CommandArgs {
 }
 
 impl CommandArgs {
-    pub fn evaluate_once(
+    pub async fn evaluate_once(
         self,
         registry: &CommandRegistry,
     ) -> Result<EvaluatedWholeStreamCommandArgs, ShellError> {
@@ -125,7 +190,7 @@ impl CommandArgs {
         let ctrl_c = self.ctrl_c.clone();
         let shell_manager = self.shell_manager.clone();
         let input = self.input;
-        let call_info = self.call_info.evaluate(registry)?;
+        let call_info = self.call_info.evaluate(registry).await?;
 
         Ok(EvaluatedWholeStreamCommandArgs::new(
             host,
@@ -136,7 +201,7 @@ impl CommandArgs {
         ))
     }
 
-    pub fn evaluate_once_with_scope(
+    pub async fn evaluate_once_with_scope(
         self,
         registry: &CommandRegistry,
         scope: &Scope,
@@ -150,7 +215,7 @@ impl CommandArgs {
             args: self.call_info.args,
             scope: scope.clone(),
         };
-        let call_info = call_info.evaluate(registry)?;
+        let call_info = call_info.evaluate(registry).await?;
 
         Ok(EvaluatedWholeStreamCommandArgs::new(
             host,
@@ -161,7 +226,7 @@ impl CommandArgs {
         ))
     }
 
-    pub fn process<'de, T: Deserialize<'de>, O: ToOutputStream>(
+    pub async fn process<'de, T: Deserialize<'de>, O: ToOutputStream>(
         self,
         registry: &CommandRegistry,
         callback: fn(T, RunnableContext) -> Result<O, ShellError>,
@@ -169,7 +234,8 @@ impl CommandArgs {
         let shell_manager = self.shell_manager.clone();
         let host = self.host.clone();
         let ctrl_c = self.ctrl_c.clone();
-        let args = self.evaluate_once(registry)?;
+        let current_errors = self.current_errors.clone();
+        let args = self.evaluate_once(registry).await?;
         let call_info = args.call_info.clone();
         let (input, args) = args.split();
         let name_tag = args.call_info.name_tag;
@@ -184,53 +250,84 @@ impl CommandArgs {
                 name: name_tag,
                 host,
                 ctrl_c,
+                current_errors,
             },
             callback,
         })
     }
 
-    pub fn process_raw<'de, T: Deserialize<'de>>(
+    /// Lets a command opt into per-row ("filter") semantics without being a
+    /// separate command kind: the unevaluated call is re-evaluated against
+    /// each `$it` from `input`, and `callback` is invoked once per row, with
+    /// the resulting streams flattened back into one `OutputStream`. This is
+    /// the combinator `PerItemCommand` implementations used to get for free
+    /// from `Command::run_helper`. Each row is awaited on before producing
+    /// its `ReturnValue`s, rather than blocking the executor inside a
+    /// synchronous `map`.
+    pub fn process_each(
         self,
         registry: &CommandRegistry,
-        callback: fn(T, RunnableContext, RawCommandArgs) -> Result<OutputStream, ShellError>,
-    ) -> Result<RunnableRawArgs<T>, ShellError> {
-        let raw_args = RawCommandArgs {
-            host: self.host.clone(),
-            ctrl_c: self.ctrl_c.clone(),
-            shell_manager: self.shell_manager.clone(),
-            call_info: self.call_info.clone(),
+        callback: fn(&CallInfo, &ItemContext, Value) -> Result<OutputStream, ShellError>,
+    ) -> Result<OutputStream, ShellError> {
+        let call_info = self.call_info;
+        let registry = registry.clone();
+        let context = ItemContext {
+            host: self.host,
+            ctrl_c: self.ctrl_c,
+            shell_manager: self.shell_manager,
+            current_errors: self.current_errors,
         };
 
-        let shell_manager = self.shell_manager.clone();
-        let host = self.host.clone();
-        let ctrl_c = self.ctrl_c.clone();
-        let args = self.evaluate_once(registry)?;
-        let call_info = args.call_info.clone();
-
-        let (input, args) = args.split();
-        let name_tag = args.call_info.name_tag;
-        let mut deserializer = ConfigDeserializer::from_call_info(call_info);
+        let out = self
+            .input
+            .then(move |x| {
+                let call_info = call_info.clone();
+                let registry = registry.clone();
+                let context = ItemContext {
+                    host: context.host.clone(),
+                    ctrl_c: context.ctrl_c.clone(),
+                    shell_manager: context.shell_manager.clone(),
+                    current_errors: context.current_errors.clone(),
+                };
+
+                async move {
+                    let evaluated = UnevaluatedCallInfo {
+                        args: call_info.args.clone(),
+                        name_tag: call_info.name_tag.clone(),
+                        scope: call_info.scope.clone().set_it(x.clone()),
+                    }
+                    .evaluate(&registry)
+                    .await;
+
+                    match evaluated {
+                        Ok(evaluated) => match callback(&evaluated, &context, x) {
+                            Ok(o) => o,
+                            Err(e) => {
+                                context.current_errors.lock().push(e.clone());
+                                futures::stream::iter(vec![ReturnValue::Err(e)])
+                                    .to_output_stream()
+                            }
+                        },
+                        Err(e) => {
+                            context.current_errors.lock().push(e.clone());
+                            futures::stream::iter(vec![ReturnValue::Err(e)]).to_output_stream()
+                        }
+                    }
+                }
+            })
+            .flatten();
 
-        Ok(RunnableRawArgs {
-            args: T::deserialize(&mut deserializer)?,
-            context: RunnableContext {
-                input,
-                commands: registry.clone(),
-                shell_manager,
-                name: name_tag,
-                host,
-                ctrl_c,
-            },
-            raw_args,
-            callback,
-        })
+        Ok(out.to_output_stream())
     }
 }
 
-pub struct RunnablePerItemContext {
-    pub shell_manager: ShellManager,
-    pub name: Tag,
+/// The subset of `CommandArgs` a `process_each` callback needs once the
+/// per-row `CallInfo` has already been evaluated.
+pub struct ItemContext {
+    pub host: Arc<parking_lot::Mutex<Box<dyn Host>>>,
     pub ctrl_c: Arc<AtomicBool>,
+    pub shell_manager: ShellManager,
+    pub current_errors: Arc<parking_lot::Mutex<Vec<ShellError>>>,
 }
 
 pub struct RunnableContext {
@@ -240,6 +337,7 @@ pub struct RunnableContext {
     pub ctrl_c: Arc<AtomicBool>,
     pub commands: CommandRegistry,
     pub name: Tag,
+    pub current_errors: Arc<parking_lot::Mutex<Vec<ShellError>>>,
 }
 
 impl RunnableContext {
@@ -248,18 +346,6 @@ impl RunnableContext {
     }
 }
 
-pub struct RunnablePerItemArgs<T> {
-    args: T,
-    context: RunnablePerItemContext,
-    callback: fn(T, &RunnablePerItemContext) -> Result<OutputStream, ShellError>,
-}
-
-impl<T> RunnablePerItemArgs<T> {
-    pub fn run(self) -> Result<OutputStream, ShellError> {
-        (self.callback)(self.args, &self.context)
-    }
-}
-
 pub struct RunnableArgs<T, O: ToOutputStream> {
     args: T,
     context: RunnableContext,
@@ -267,27 +353,11 @@ pub struct RunnableArgs<T, O: ToOutputStream> {
 }
 
 impl<T, O: ToOutputStream> RunnableArgs<T, O> {
-    pub fn run(self) -> Result<OutputStream, ShellError> {
+    pub async fn run(self) -> Result<OutputStream, ShellError> {
         (self.callback)(self.args, self.context).map(|v| v.to_output_stream())
     }
 }
 
-pub struct RunnableRawArgs<T> {
-    args: T,
-    raw_args: RawCommandArgs,
-    context: RunnableContext,
-    callback: fn(T, RunnableContext, RawCommandArgs) -> Result<OutputStream, ShellError>,
-}
-
-impl<T> RunnableRawArgs<T> {
-    pub fn run(self) -> OutputStream {
-        match (self.callback)(self.args, self.context, self.raw_args) {
-            Ok(stream) => stream,
-            Err(err) => OutputStream::one(Err(err)),
-        }
-    }
-}
-
 pub struct EvaluatedWholeStreamCommandArgs {
     pub args: EvaluatedCommandArgs,
     pub input: InputStream,
@@ -384,7 +454,11 @@ impl EvaluatedCommandArgs {
     /// Get the nth positional argument, error if not possible
     pub fn expect_nth(&self, pos: usize) -> Result<&Value, ShellError> {
         match self.call_info.args.nth(pos) {
-            None => Err(ShellError::unimplemented("Better error: expect_nth")),
+            None => Err(ShellError::labeled_error(
+                "Expected more positional arguments",
+                format!("missing argument at position {}", pos),
+                &self.call_info.name_tag,
+            )),
             Some(item) => Ok(item),
         }
     }
@@ -396,29 +470,60 @@ impl EvaluatedCommandArgs {
     pub fn has(&self, name: &str) -> bool {
         self.call_info.args.has(name)
     }
-}
 
-pub trait WholeStreamCommand: Send + Sync {
-    fn name(&self) -> &str;
+    /// The nth positional argument, converted to `T`. Unlike deserializing
+    /// a whole args struct through `ConfigDeserializer`, a type mismatch is
+    /// spanned to that one argument; a missing argument is spanned to the
+    /// command name.
+    pub fn req<T: FromValue>(&self, pos: usize) -> Result<T, ShellError> {
+        T::from_value(self.expect_nth(pos)?)
+    }
 
-    fn signature(&self) -> Signature {
-        Signature::new(self.name()).desc(self.usage()).filter()
+    pub fn opt<T: FromValue>(&self, pos: usize) -> Result<Option<T>, ShellError> {
+        match self.nth(pos) {
+            None => Ok(None),
+            Some(v) => Ok(Some(T::from_value(v)?)),
+        }
     }
 
-    fn usage(&self) -> &str;
+    /// Every positional argument from `starting_pos` onward, converted to `T`.
+    pub fn rest<T: FromValue>(&self, starting_pos: usize) -> Result<Vec<T>, ShellError> {
+        let mut result = vec![];
+        let mut pos = starting_pos;
 
-    fn run(
-        &self,
-        args: CommandArgs,
-        registry: &CommandRegistry,
-    ) -> Result<OutputStream, ShellError>;
+        while let Some(v) = self.nth(pos) {
+            result.push(T::from_value(v)?);
+            pos += 1;
+        }
 
-    fn is_binary(&self) -> bool {
-        false
+        Ok(result)
+    }
+
+    pub fn req_named<T: FromValue>(&self, name: &str) -> Result<T, ShellError> {
+        match self.get(name) {
+            None => Err(ShellError::labeled_error(
+                "Missing required flag",
+                format!("--{} is required", name),
+                &self.call_info.name_tag,
+            )),
+            Some(v) => T::from_value(v),
+        }
+    }
+
+    pub fn opt_named<T: FromValue>(&self, name: &str) -> Result<Option<T>, ShellError> {
+        match self.get(name) {
+            None => Ok(None),
+            Some(v) => Ok(Some(T::from_value(v)?)),
+        }
+    }
+
+    pub fn has_flag(&self, name: &str) -> bool {
+        self.has(name)
     }
 }
 
-pub trait PerItemCommand: Send + Sync {
+#[async_trait]
+pub trait WholeStreamCommand: Send + Sync {
     fn name(&self) -> &str;
 
     fn signature(&self) -> Signature {
@@ -427,141 +532,96 @@ pub trait PerItemCommand: Send + Sync {
 
     fn usage(&self) -> &str;
 
-    fn run(
+    async fn run(
         &self,
-        call_info: &CallInfo,
+        args: CommandArgs,
         registry: &CommandRegistry,
-        raw_args: &RawCommandArgs,
-        input: Value,
     ) -> Result<OutputStream, ShellError>;
 
     fn is_binary(&self) -> bool {
         false
     }
-}
 
-pub enum Command {
-    WholeStream(Arc<dyn WholeStreamCommand>),
-    PerItem(Arc<dyn PerItemCommand>),
+    /// Coarse capability tags describing what this command is able to do
+    /// (e.g. `"filesystem"`, `"net"`, `"env-write"`). A capability-restricted
+    /// `ScopeFrame` denies a command whose tags intersect its deny-list, even
+    /// if the command is visible by name. Commands with no meaningful side
+    /// effects can leave this as the default empty slice.
+    fn capabilities(&self) -> &'static [&'static str] {
+        &[]
+    }
 }
 
+/// A thin, single-kind wrapper around a `WholeStreamCommand`. Per-item
+/// ("filter") semantics are no longer a separate command kind; a command
+/// that wants to evaluate its arguments against each `$it` opts in from
+/// within its own `run` by calling `args.process_each(...)`.
+pub struct Command(Arc<dyn WholeStreamCommand>);
+
 impl PrettyDebugWithSource for Command {
     fn pretty_debug(&self, source: &str) -> DebugDocBuilder {
-        match self {
-            Command::WholeStream(command) => b::typed(
-                "whole stream command",
-                b::description(command.name())
-                    + b::space()
-                    + b::equals()
-                    + b::space()
-                    + command.signature().pretty_debug(source),
-            ),
-            Command::PerItem(command) => b::typed(
-                "per item command",
-                b::description(command.name())
-                    + b::space()
-                    + b::equals()
-                    + b::space()
-                    + command.signature().pretty_debug(source),
-            ),
-        }
+        b::typed(
+            "whole stream command",
+            b::description(self.0.name())
+                + b::space()
+                + b::equals()
+                + b::space()
+                + self.0.signature().pretty_debug(source),
+        )
     }
 }
 
 impl std::fmt::Debug for Command {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Command::WholeStream(command) => write!(f, "WholeStream({})", command.name()),
-            Command::PerItem(command) => write!(f, "PerItem({})", command.name()),
-        }
+        write!(f, "WholeStream({})", self.0.name())
     }
 }
 
 impl Command {
     pub fn name(&self) -> &str {
-        match self {
-            Command::WholeStream(command) => command.name(),
-            Command::PerItem(command) => command.name(),
-        }
+        self.0.name()
     }
 
     pub fn signature(&self) -> Signature {
-        match self {
-            Command::WholeStream(command) => command.signature(),
-            Command::PerItem(command) => command.signature(),
-        }
+        self.0.signature()
     }
 
     pub fn usage(&self) -> &str {
-        match self {
-            Command::WholeStream(command) => command.usage(),
-            Command::PerItem(command) => command.usage(),
-        }
+        self.0.usage()
     }
 
-    pub fn run(&self, args: CommandArgs, registry: &CommandRegistry) -> OutputStream {
-        if args.call_info.switch_present("help") {
+    pub async fn run(&self, args: CommandArgs, registry: &CommandRegistry) -> OutputStream {
+        let tracing = args.call_info.switch_present("trace");
+        let current_errors = args.current_errors.clone();
+
+        let output = if args.call_info.switch_present("help") {
             get_help(self.name(), self.usage(), self.signature()).into()
         } else {
-            match self {
-                Command::WholeStream(command) => match command.run(args, registry) {
-                    Ok(stream) => stream,
-                    Err(err) => OutputStream::one(Err(err)),
-                },
-                Command::PerItem(command) => {
-                    self.run_helper(command.clone(), args, registry.clone())
-                }
+            match self.0.run(args, registry).await {
+                Ok(stream) => stream,
+                Err(err) => OutputStream::one(Err(err)),
             }
-        }
-    }
-
-    fn run_helper(
-        &self,
-        command: Arc<dyn PerItemCommand>,
-        args: CommandArgs,
-        registry: CommandRegistry,
-    ) -> OutputStream {
-        let raw_args = RawCommandArgs {
-            host: args.host,
-            ctrl_c: args.ctrl_c,
-            shell_manager: args.shell_manager,
-            call_info: args.call_info,
         };
 
-        let out = args
-            .input
-            .map(move |x| {
-                let call_info = UnevaluatedCallInfo {
-                    args: raw_args.call_info.args.clone(),
-                    name_tag: raw_args.call_info.name_tag.clone(),
-                    scope: raw_args.call_info.scope.clone().set_it(x.clone()),
-                }
-                .evaluate(&registry);
-                // let call_info = raw_args
-                //     .clone()
-                //     .call_info
-                //     .evaluate(&registry, &Scope::it_value(x.clone()));
-
-                match call_info {
-                    Ok(call_info) => match command.run(&call_info, &registry, &raw_args, x) {
-                        Ok(o) => o,
-                        Err(e) => {
-                            futures::stream::iter(vec![ReturnValue::Err(e)]).to_output_stream()
-                        }
-                    },
-                    Err(e) => futures::stream::iter(vec![ReturnValue::Err(e)]).to_output_stream(),
-                }
-            })
-            .flatten();
+        if tracing {
+            print_trace();
+        }
 
-        out.to_output_stream()
+        let recorded = current_errors.lock();
+        if !recorded.is_empty() {
+            eprintln!("{} row(s) failed while running `{}`", recorded.len(), self.name());
+        }
+        drop(recorded);
+
+        output
     }
 
     pub fn is_binary(&self) -> bool {
-        match self {
-            Command::WholeStream(command) => command.is_binary(),
-            Command::PerItem(command) => command.is_binary(),
-        }
+        self.0.is_binary()
+    }
+
+    pub fn capabilities(&self) -> &'static [&'static str] {
+        self.0.capabilities()
     }
 }
 
@@ -570,6 +630,7 @@ pub struct FnFilterCommand {
     func: fn(EvaluatedFilterCommandArgs) -> Result<OutputStream, ShellError>,
 }
 
+#[async_trait]
 impl WholeStreamCommand for FnFilterCommand {
     fn name(&self) -> &str {
         &self.name
@@ -579,7 +640,7 @@ impl WholeStreamCommand for FnFilterCommand {
         "usage"
     }
 
-    fn run(
+    async fn run(
         &self,
         args: CommandArgs,
         registry: &CommandRegistry,
@@ -596,23 +657,26 @@ impl WholeStreamCommand for FnFilterCommand {
         let registry: CommandRegistry = registry.clone();
         let func = self.func;
 
-        let result = input.map(move |it| {
+        let result = input.then(move |it| {
             let registry = registry.clone();
-            let call_info = match call_info.clone().evaluate_with_new_it(&registry, &it) {
-                Err(err) => return OutputStream::from(vec![Err(err)]).values,
-                Ok(args) => args,
-            };
-
-            let args = EvaluatedFilterCommandArgs::new(
-                host.clone(),
-                ctrl_c.clone(),
-                shell_manager.clone(),
-                call_info,
-            );
-
-            match func(args) {
-                Err(err) => OutputStream::from(vec![Err(err)]).values,
-                Ok(stream) => stream.values,
+            let call_info = call_info.clone();
+            let host = host.clone();
+            let ctrl_c = ctrl_c.clone();
+            let shell_manager = shell_manager.clone();
+
+            async move {
+                let call_info = match call_info.evaluate_with_new_it(&registry, &it).await {
+                    Err(err) => return OutputStream::from(vec![Err(err)]).values,
+                    Ok(args) => args,
+                };
+
+                let args =
+                    EvaluatedFilterCommandArgs::new(host, ctrl_c, shell_manager, call_info);
+
+                match func(args) {
+                    Err(err) => OutputStream::from(vec![Err(err)]).values,
+                    Ok(stream) => stream.values,
+                }
             }
         });
 
@@ -624,9 +688,5 @@ impl WholeStreamCommand for FnFilterCommand {
 }
 
 pub fn whole_stream_command(command: impl WholeStreamCommand + 'static) -> Arc<Command> {
-    Arc::new(Command::WholeStream(Arc::new(command)))
-}
-
-pub fn per_item_command(command: impl PerItemCommand + 'static) -> Arc<Command> {
-    Arc::new(Command::PerItem(Arc::new(command)))
+    Arc::new(Command(Arc::new(command)))
 }
@@ -1,15 +1,19 @@
 use crate::commands::WholeStreamCommand;
 use crate::context::CommandRegistry;
 use crate::prelude::*;
+use nu_engine::evaluate_baseline_expr;
 use nu_errors::ShellError;
-use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue};
-use nu_source::Tagged;
+use nu_protocol::{
+    hir::CapturedBlock, hir::ClassifiedCommand, hir::SpannedExpression, Primitive, ReturnSuccess,
+    Signature, SyntaxShape, UntaggedValue, Value,
+};
+use std::convert::TryFrom;
 
 pub struct First;
 
 #[derive(Deserialize)]
 pub struct FirstArgs {
-    rows: Option<Tagged<usize>>,
+    rows: Option<Value>,
 }
 
 #[async_trait]
@@ -21,13 +25,14 @@ impl WholeStreamCommand for First {
     fn signature(&self) -> Signature {
         Signature::build("first").optional(
             "rows",
-            SyntaxShape::Int,
-            "starting from the front, the number of rows to return",
+            SyntaxShape::Any,
+            "starting from the front, the number of rows to return, or a block run against \
+             each row that stops the stream at the first falsy result",
         )
     }
 
     fn usage(&self) -> &str {
-        "Show only the first number of rows."
+        "Show only the first number of rows, or the leading run of rows matching a block."
     }
 
     async fn run(
@@ -53,31 +58,123 @@ impl WholeStreamCommand for First {
                     UntaggedValue::int(2).into(),
                 ]),
             },
+            Example {
+                description: "Return the leading rows that satisfy a condition",
+                example: "echo [1 2 3 1] | first { |row| $row < 3 }",
+                result: Some(vec![
+                    UntaggedValue::int(1).into(),
+                    UntaggedValue::int(2).into(),
+                ]),
+            },
         ]
     }
 }
 
 async fn first(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
     let registry = registry.clone();
+    let ctx = Context::from_args(&args, &registry);
+    let name_tag = args.call_info.name_tag.clone();
     let (FirstArgs { rows }, mut input) = args.process(&registry).await?;
-    let mut rows_desired = if let Some(quantity) = rows {
-        *quantity
-    } else {
-        1
-    };
-
-    let mut values_vec_deque = VecDeque::new();
-
-    while let Some(input) = input.next().await {
-        if rows_desired > 0 {
-            values_vec_deque.push_back(ReturnSuccess::value(input));
-            rows_desired -= 1;
-        } else {
-            break;
+
+    match rows.map(|v| v.value) {
+        Some(UntaggedValue::Block(block)) => {
+            let condition = extract_condition(&block, &name_tag)?;
+
+            let stream = async_stream! {
+                while let Some(row) = input.next().await {
+                    match eval_condition(&condition, &block, &ctx, &row) {
+                        Ok(true) => yield ReturnSuccess::value(row),
+                        Ok(false) => break,
+                        Err(e) => {
+                            yield Err(e);
+                            break;
+                        }
+                    }
+                }
+            };
+
+            Ok(stream.to_output_stream())
+        }
+        other => {
+            let mut rows_desired = match other {
+                Some(UntaggedValue::Primitive(Primitive::Int(n))) => {
+                    usize::try_from(n).map_err(|_| {
+                        ShellError::labeled_error(
+                            "Expected a non-negative row count",
+                            "must not be negative",
+                            &name_tag,
+                        )
+                    })?
+                }
+                None => 1,
+                Some(_) => {
+                    return Err(ShellError::labeled_error(
+                        "Expected a row count or a block",
+                        "expected an integer or a block",
+                        &name_tag,
+                    ))
+                }
+            };
+
+            let mut values_vec_deque = VecDeque::new();
+
+            while let Some(input) = input.next().await {
+                if rows_desired > 0 {
+                    values_vec_deque.push_back(ReturnSuccess::value(input));
+                    rows_desired -= 1;
+                } else {
+                    break;
+                }
+            }
+
+            Ok(futures::stream::iter(values_vec_deque).to_output_stream())
         }
     }
+}
+
+///Extracts the single condition expression out of the block `first` was
+///given, the same shape `keep until`/`all?` parse their block argument into.
+fn extract_condition(
+    block: &CapturedBlock,
+    tag: &Tag,
+) -> Result<SpannedExpression, ShellError> {
+    match block
+        .block
+        .block
+        .get(0)
+        .and_then(|group| group.pipelines.get(0))
+        .and_then(|pipeline| pipeline.list.get(0))
+    {
+        Some(ClassifiedCommand::Expr(expr)) => Ok(expr.clone()),
+        _ => Err(ShellError::labeled_error(
+            "Expected a condition",
+            "expected a single expression",
+            tag,
+        )),
+    }
+}
+
+///Evaluates `condition` against `row`, binding the block's named parameter
+///(or `$it` if the block took none) for the duration, mirroring the
+///`CapturedBlock` + `evaluate_baseline_expr` combination `keep until` and
+///`all?` already use for per-row predicates.
+fn eval_condition(
+    condition: &SpannedExpression,
+    block: &CapturedBlock,
+    ctx: &Context,
+    row: &Value,
+) -> Result<bool, ShellError> {
+    ctx.scope.enter_scope();
+    ctx.scope.add_vars(&block.captured.entries);
+    match block.block.params.positional.first() {
+        Some((arg, _)) => ctx.scope.add_var(arg.name(), row.clone()),
+        None => ctx.scope.add_var("$it", row.clone()),
+    }
+
+    let result = evaluate_baseline_expr(condition, ctx);
+    ctx.scope.exit_scope();
 
-    Ok(futures::stream::iter(values_vec_deque).to_output_stream())
+    result.map(|v| v.is_true())
 }
 
 #[cfg(test)]
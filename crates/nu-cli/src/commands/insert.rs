@@ -1,8 +1,12 @@
 use crate::commands::WholeStreamCommand;
 use crate::context::CommandRegistry;
 use crate::prelude::*;
+use nu_engine::evaluate_baseline_expr;
 use nu_errors::ShellError;
-use nu_protocol::{ColumnPath, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_protocol::{
+    hir::CapturedBlock, hir::ClassifiedCommand, ColumnPath, ReturnSuccess, Signature, SyntaxShape,
+    UntaggedValue, Value,
+};
 use nu_value_ext::ValueExt;
 
 pub struct Insert;
@@ -13,6 +17,7 @@ pub struct InsertArgs {
     value: Value,
 }
 
+#[async_trait]
 impl WholeStreamCommand for Insert {
     fn name(&self) -> &str {
         "insert"
@@ -27,16 +32,16 @@ impl WholeStreamCommand for Insert {
             )
             .required(
                 "value",
-                SyntaxShape::String,
-                "the value to give the cell(s)",
+                SyntaxShape::Any,
+                "the value to give the cell(s), or a block to compute it from each row",
             )
     }
 
     fn usage(&self) -> &str {
-        "Insert a new column with a given value."
+        "Insert a new column with a given value, or with the value of a block run against each row."
     }
 
-    fn run(
+    async fn run(
         &self,
         args: CommandArgs,
         registry: &CommandRegistry,
@@ -47,17 +52,31 @@ impl WholeStreamCommand for Insert {
 
 fn insert(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
     let registry = registry.clone();
+    let ctx = Context::from_args(&args, &registry);
 
     let stream = async_stream! {
         let (InsertArgs { column, value }, mut input) = args.process(&registry).await?;
-        while let Some(value) = input.next().await {
-            match value {
-                value @ Value {
+
+        let block = match &value.value {
+            UntaggedValue::Block(captured) => Some(captured.clone()),
+            _ => None,
+        };
+
+        while let Some(row) = input.next().await {
+            match row {
+                row @ Value {
                     value: UntaggedValue::Row(_),
                     ..
-                } => match value.insert_data_at_column_path(&column, value.clone()) {
-                    Ok(v) => yield Ok(ReturnSuccess::Value(v)),
-                    Err(err) => yield Err(err),
+                } => {
+                    let computed = match &block {
+                        Some(block) => compute_per_row(block, &ctx, &row),
+                        None => Ok(value.clone()),
+                    };
+
+                    match computed.and_then(|v| row.insert_data_at_column_path(&column, v)) {
+                        Ok(v) => yield Ok(ReturnSuccess::Value(v)),
+                        Err(err) => yield Err(err),
+                    }
                 },
 
                 Value { tag, ..} => {
@@ -74,3 +93,34 @@ fn insert(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream,
     };
     Ok(stream.to_output_stream())
 }
+
+///Evaluates `block`'s single expression against `row`, binding `$it` (and
+///the block's captured vars) for the duration, mirroring the per-row
+///evaluation `all?` already does with the same `CapturedBlock` +
+///`evaluate_baseline_expr` combination.
+fn compute_per_row(block: &CapturedBlock, ctx: &Context, row: &Value) -> Result<Value, ShellError> {
+    let expr = match block
+        .block
+        .block
+        .get(0)
+        .and_then(|group| group.pipelines.get(0))
+        .and_then(|pipeline| pipeline.list.get(0))
+    {
+        Some(ClassifiedCommand::Expr(expr)) => expr.clone(),
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Expected an expression",
+                "expected a single expression to compute the column's value",
+                &row.tag,
+            ))
+        }
+    };
+
+    ctx.scope.enter_scope();
+    ctx.scope.add_vars(&block.captured.entries);
+    ctx.scope.add_var("$it", row.clone());
+    let result = evaluate_baseline_expr(&expr, ctx);
+    ctx.scope.exit_scope();
+
+    result
+}
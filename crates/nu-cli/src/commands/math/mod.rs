@@ -8,7 +8,9 @@ pub mod max;
 pub mod median;
 pub mod min;
 pub mod mode;
+pub mod percentile;
 pub mod product;
+pub mod quantile;
 pub mod round;
 pub mod stddev;
 pub mod sum;
@@ -27,7 +29,9 @@ pub use max::SubCommand as MathMaximum;
 pub use median::SubCommand as MathMedian;
 pub use min::SubCommand as MathMinimum;
 pub use mode::SubCommand as MathMode;
+pub use percentile::SubCommand as MathPercentile;
 pub use product::SubCommand as MathProduct;
+pub use quantile::SubCommand as MathQuantile;
 pub use round::SubCommand as MathRound;
 pub use stddev::SubCommand as MathStddev;
 pub use sum::SubCommand as MathSummation;
@@ -0,0 +1,119 @@
+use super::reducers::quantile;
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Dictionary, ReturnSuccess, ReturnValue, Signature, SyntaxShape, UntaggedValue};
+use nu_source::Tagged;
+
+use indexmap::map::IndexMap;
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "math quantile"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math quantile").required(
+            "quantile",
+            SyntaxShape::Number,
+            "the quantile to compute, between 0 and 1",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Computes the quantile of a list of numbers"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let registry = registry.clone();
+        let args = args.evaluate_once(&registry).await?;
+        let name_tag = args.name_tag();
+        let quantile: Tagged<f64> = args.req(0)?;
+
+        if !(0.0..=1.0).contains(&quantile.item) {
+            return Err(ShellError::labeled_error(
+                "Invalid quantile",
+                "expected a value between 0 and 1",
+                &quantile.tag,
+            ));
+        }
+
+        let (input, _) = args.parts();
+        quantile_command(input, quantile.item, name_tag).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Get the 0.95 quantile of a list of numbers",
+            example: "echo [1 2 3 4 5] | math quantile 0.95",
+            result: None,
+        }]
+    }
+}
+
+async fn quantile_command(
+    mut input: InputStream,
+    q: f64,
+    tag: Tag,
+) -> Result<OutputStream, ShellError> {
+    let values: Vec<Value> = input.drain_vec().await;
+
+    let stream = async_stream! {
+        if values.iter().all(|v| matches!(v.value, UntaggedValue::Primitive(_))) {
+            match quantile(&values, q, &tag) {
+                Ok(value) => yield ReturnSuccess::value(value),
+                Err(err) => yield Err(err),
+            }
+        } else {
+            let mut column_values = IndexMap::new();
+            for value in &values {
+                if let UntaggedValue::Row(row_dict) = &value.value {
+                    for (key, value) in row_dict.entries.iter() {
+                        column_values
+                            .entry(key.clone())
+                            .and_modify(|v: &mut Vec<Value>| v.push(value.clone()))
+                            .or_insert_with(|| vec![value.clone()]);
+                    }
+                }
+            }
+
+            let mut column_totals = IndexMap::new();
+            for (col_name, col_vals) in column_values {
+                match quantile(&col_vals, q, &tag) {
+                    Ok(value) => {
+                        column_totals.insert(col_name, value);
+                    }
+                    Err(err) => yield Err(err),
+                };
+            }
+
+            yield ReturnSuccess::value(
+                UntaggedValue::Row(Dictionary { entries: column_totals }).into_value(tag),
+            )
+        }
+    };
+
+    let stream: BoxStream<'static, ReturnValue> = stream.boxed();
+
+    Ok(stream.to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -0,0 +1,63 @@
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, UntaggedValue, Value};
+use nu_source::Tag;
+use num_traits::cast::ToPrimitive;
+
+/// Linear-interpolation quantile (the method NumPy/R call "R-7"): for the
+/// sorted values `v[0..n]` and a fraction `q` in `[0, 1]`, interpolates
+/// between the two nearest ranks instead of rounding to the nearest one,
+/// so e.g. the median of an even-length list isn't forced onto a single
+/// element.
+pub fn quantile(values: &[Value], q: f64, tag: &Tag) -> Result<Value, ShellError> {
+    if values.is_empty() {
+        return Err(ShellError::labeled_error(
+            "Attempted to compute the quantile of an empty table",
+            "expected input",
+            tag,
+        ));
+    }
+
+    let mut sorted: Vec<f64> = values
+        .iter()
+        .map(|value| as_f64(value))
+        .collect::<Result<Vec<_>, _>>()?;
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+    let n = sorted.len();
+    let pos = q * (n - 1) as f64;
+    let lo = pos.floor() as usize;
+    let hi = pos.ceil() as usize;
+
+    let interpolated = sorted[lo] + (pos - lo as f64) * (sorted[hi] - sorted[lo]);
+
+    let all_int = values
+        .iter()
+        .all(|value| matches!(&value.value, UntaggedValue::Primitive(Primitive::Int(_))));
+
+    let result = if all_int && lo == hi {
+        UntaggedValue::int(interpolated.round() as i64)
+    } else {
+        UntaggedValue::decimal_from_float(interpolated, tag.span)
+    };
+
+    Ok(result.into_value(tag.clone()))
+}
+
+fn as_f64(value: &Value) -> Result<f64, ShellError> {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::Int(n)) => Ok(n
+            .to_f64()
+            .expect("Internal error: protocol did not use compatible decimal")),
+        UntaggedValue::Primitive(Primitive::BigInt(n)) => Ok(n
+            .to_f64()
+            .expect("Internal error: protocol did not use compatible decimal")),
+        UntaggedValue::Primitive(Primitive::Decimal(n)) => Ok(n
+            .to_f64()
+            .expect("Internal error: protocol did not use compatible decimal")),
+        _ => Err(ShellError::labeled_error(
+            "Unsupported type for quantile",
+            "expected a numeric value",
+            &value.tag,
+        )),
+    }
+}
@@ -1,4 +1,5 @@
 use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
 use crate::prelude::*;
 use nu_errors::ShellError;
 use nu_protocol::{ReturnSuccess, Signature, UntaggedValue};
@@ -19,7 +20,11 @@ impl WholeStreamCommand for Command {
         "Generate random values"
     }
 
-    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+    async fn run(
+        &self,
+        args: CommandArgs,
+        _registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
         Ok(OutputStream::one(Ok(ReturnSuccess::Value(
             UntaggedValue::string(crate::commands::help::get_help(
                 &Command,
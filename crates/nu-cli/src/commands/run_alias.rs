@@ -4,7 +4,8 @@ use crate::prelude::*;
 
 use derive_new::new;
 use nu_errors::ShellError;
-use nu_protocol::{hir::Block, PositionalType, Signature, UntaggedValue, Value};
+use nu_protocol::{hir::Block, NamedType, PositionalType, Signature, UntaggedValue, Value};
+use nu_source::Tag;
 
 #[derive(new, Clone)]
 pub struct AliasCommand {
@@ -43,26 +44,60 @@ impl WholeStreamCommand for AliasCommand {
         let mut scope = call_info.scope.clone();
         let evaluated = call_info.evaluate(&registry).await?;
 
-        if let Some(positional) = &evaluated.args.positional {
-            for (idx, (pos_type, _)) in self.sig.positional.iter().enumerate() {
-                let arg = &positional[idx];
-                match pos_type {
-                    PositionalType::Mandatory(name, _) | PositionalType::Optional(name, _) => {
+        let name_tag = &call_info.name_tag;
+        let positional = evaluated.args.positional.clone().unwrap_or_default();
+        for (idx, (pos_type, _)) in self.sig.positional.iter().enumerate() {
+            match pos_type {
+                PositionalType::Mandatory(name, _) => {
+                    if let Some(arg) = positional.get(idx) {
                         scope.vars.insert(name.clone(), arg.clone());
                     }
                 }
+                PositionalType::Optional(name, _, default) => {
+                    let value = match positional.get(idx) {
+                        Some(arg) => arg.clone(),
+                        None => default_to_value(default, name_tag),
+                    };
+                    scope.vars.insert(name.clone(), value);
+                }
             }
-            if let Some((_, desc)) = &self.sig.rest_positional {
-                let var_arg_idx = self.sig.positional.len();
-                if var_arg_idx < positional.len() {
-                    let var_arg_val = Value {
-                        value: UntaggedValue::Table(positional[var_arg_idx..].to_vec()),
-                        tag: positional[var_arg_idx]
-                            .tag
-                            .until(&positional.last().unwrap_or(&Value::nothing()).tag),
+        }
+        if let Some((_, desc)) = &self.sig.rest_positional {
+            let var_arg_idx = self.sig.positional.len();
+            let var_arg_val = if var_arg_idx < positional.len() {
+                Value {
+                    value: UntaggedValue::Table(positional[var_arg_idx..].to_vec()),
+                    tag: positional[var_arg_idx]
+                        .tag
+                        .until(&positional.last().unwrap_or(&Value::nothing()).tag),
+                }
+            } else {
+                UntaggedValue::Table(vec![]).into_value(name_tag)
+            };
+            //Use description as name
+            scope.vars.insert(desc.to_string(), var_arg_val);
+        }
+
+        for (flag_name, (named_type, _)) in self.sig.named.iter() {
+            let dollar_name = format!("${}", flag_name);
+            match named_type {
+                NamedType::Switch(_) => {
+                    let has = evaluated.args.has(flag_name);
+                    scope
+                        .vars
+                        .insert(dollar_name, UntaggedValue::boolean(has).into_value(name_tag));
+                }
+                NamedType::Mandatory(_, _) => {
+                    if let Some(value) = evaluated.args.get(flag_name) {
+                        scope.vars.insert(dollar_name, value.clone());
+                    }
+                }
+                NamedType::Optional(_, _, default) => {
+                    let value = match evaluated.args.get(flag_name) {
+                        Some(value) => value.clone(),
+                        None => default_to_value(default, name_tag),
                     };
-                    //Use description as name
-                    scope.vars.insert(desc.to_string(), var_arg_val);
+                    scope.vars.insert(dollar_name, value);
                 }
             }
         }
@@ -86,3 +121,35 @@ impl WholeStreamCommand for AliasCommand {
         Vec::new()
     }
 }
+
+/// Turns a declared positional or flag default (the raw literal text
+/// captured at `alias` definition time, e.g. the `10` in `[count=10]`)
+/// into the `Value` bound into scope when the caller omits that argument.
+/// With no default at all, the slot is simply `$nothing`.
+fn default_to_value(default: &Option<String>, tag: &Tag) -> Value {
+    match default {
+        Some(literal) => literal_to_value(literal, tag),
+        None => Value::nothing(),
+    }
+}
+
+/// Aliases don't carry type annotations the way `def` signatures do, so a
+/// default literal is coerced by trying the concrete types a user is likely
+/// to write it as (int, decimal, bool) before falling back to a plain
+/// string, with one layer of surrounding double quotes stripped first.
+fn literal_to_value(literal: &str, tag: &Tag) -> Value {
+    let literal = literal
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .unwrap_or(literal);
+
+    if let Ok(n) = literal.parse::<i64>() {
+        UntaggedValue::int(n).into_value(tag)
+    } else if let Ok(n) = literal.parse::<f64>() {
+        UntaggedValue::decimal_from_float(n, tag.span).into_value(tag.clone())
+    } else if let Ok(b) = literal.parse::<bool>() {
+        UntaggedValue::boolean(b).into_value(tag)
+    } else {
+        UntaggedValue::string(literal).into_value(tag)
+    }
+}
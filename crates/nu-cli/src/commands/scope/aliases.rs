@@ -0,0 +1,64 @@
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use indexmap::IndexMap;
+use nu_errors::ShellError;
+use nu_protocol::{Dictionary, ReturnSuccess, Signature, UntaggedValue};
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "scope aliases"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("scope aliases")
+    }
+
+    fn usage(&self) -> &str {
+        "View a table of the aliases defined in the current scope"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let registry = registry.clone();
+        let args = args.evaluate_once(&registry).await?;
+        let name_tag = args.name_tag();
+        let scope = registry.scope();
+
+        let rows = scope
+            .get_aliases_with_depth()
+            .into_iter()
+            .map(|(name, replacement, depth)| {
+                let expansion = replacement
+                    .iter()
+                    .map(|piece| piece.item.clone())
+                    .collect::<Vec<_>>()
+                    .join(" ");
+
+                let mut entries = IndexMap::new();
+                entries.insert(
+                    "name".to_string(),
+                    UntaggedValue::string(name).into_value(&name_tag),
+                );
+                entries.insert(
+                    "expansion".to_string(),
+                    UntaggedValue::string(expansion).into_value(&name_tag),
+                );
+                entries.insert(
+                    "frame".to_string(),
+                    UntaggedValue::int(depth as i64).into_value(&name_tag),
+                );
+
+                ReturnSuccess::value(UntaggedValue::Row(Dictionary::new(entries)).into_value(&name_tag))
+            })
+            .collect::<Vec<_>>();
+
+        Ok(futures::stream::iter(rows).to_output_stream())
+    }
+}
@@ -0,0 +1,36 @@
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, UntaggedValue};
+
+pub struct Command;
+
+#[async_trait]
+impl WholeStreamCommand for Command {
+    fn name(&self) -> &str {
+        "scope"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("scope")
+    }
+
+    fn usage(&self) -> &str {
+        "View information about the current scope"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        _registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        Ok(OutputStream::one(Ok(ReturnSuccess::Value(
+            UntaggedValue::string(crate::commands::help::get_help(
+                &Command,
+                &args.call_info.scope,
+            ))
+            .into_value(Tag::unknown()),
+        ))))
+    }
+}
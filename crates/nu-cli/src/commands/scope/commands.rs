@@ -0,0 +1,63 @@
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use indexmap::IndexMap;
+use nu_errors::ShellError;
+use nu_protocol::{Dictionary, ReturnSuccess, Signature, UntaggedValue};
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "scope commands"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("scope commands")
+    }
+
+    fn usage(&self) -> &str {
+        "View a table of the commands visible in the current scope"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let registry = registry.clone();
+        let args = args.evaluate_once(&registry).await?;
+        let name_tag = args.name_tag();
+        let scope = registry.scope();
+
+        let rows = scope
+            .get_command_names_with_depth()
+            .into_iter()
+            .map(|(name, depth)| {
+                let usage = scope
+                    .get_command(&name)
+                    .map(|command| command.usage().to_string())
+                    .unwrap_or_default();
+
+                let mut entries = IndexMap::new();
+                entries.insert(
+                    "name".to_string(),
+                    UntaggedValue::string(name).into_value(&name_tag),
+                );
+                entries.insert(
+                    "usage".to_string(),
+                    UntaggedValue::string(usage).into_value(&name_tag),
+                );
+                entries.insert(
+                    "frame".to_string(),
+                    UntaggedValue::int(depth as i64).into_value(&name_tag),
+                );
+
+                ReturnSuccess::value(UntaggedValue::Row(Dictionary::new(entries)).into_value(&name_tag))
+            })
+            .collect::<Vec<_>>();
+
+        Ok(futures::stream::iter(rows).to_output_stream())
+    }
+}
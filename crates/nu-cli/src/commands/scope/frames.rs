@@ -0,0 +1,52 @@
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use indexmap::IndexMap;
+use nu_errors::ShellError;
+use nu_protocol::{Dictionary, ReturnSuccess, Signature, UntaggedValue};
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "scope frames"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("scope frames")
+    }
+
+    fn usage(&self) -> &str {
+        "View a table of the stack of frames making up the current scope, outermost first"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let registry = registry.clone();
+        let args = args.evaluate_once(&registry).await?;
+        let name_tag = args.name_tag();
+        let scope = registry.scope();
+
+        let rows = (0..scope.frame_count())
+            .map(|depth| {
+                let mut entries = IndexMap::new();
+                entries.insert(
+                    "frame".to_string(),
+                    UntaggedValue::int(depth as i64).into_value(&name_tag),
+                );
+                entries.insert(
+                    "restricted".to_string(),
+                    UntaggedValue::boolean(scope.frame_is_restricted(depth)).into_value(&name_tag),
+                );
+
+                ReturnSuccess::value(UntaggedValue::Row(Dictionary::new(entries)).into_value(&name_tag))
+            })
+            .collect::<Vec<_>>();
+
+        Ok(futures::stream::iter(rows).to_output_stream())
+    }
+}
@@ -0,0 +1,16 @@
+//! `scope` and its subcommands turn `crate::evaluate::scope::Scope`'s
+//! accessors into nu tables, so variable/command/alias resolution (and any
+//! shadowing between frames) can be inspected from the pipeline instead of
+//! guessed at.
+
+mod aliases;
+mod command;
+mod commands;
+mod frames;
+mod variables;
+
+pub use aliases::SubCommand as ScopeAliases;
+pub use command::Command as Scope;
+pub use commands::SubCommand as ScopeCommands;
+pub use frames::SubCommand as ScopeFrames;
+pub use variables::SubCommand as ScopeVariables;
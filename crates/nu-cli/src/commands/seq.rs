@@ -86,6 +86,8 @@ async fn seq(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStre
         _input,
     ) = args.process(&registry).await?;
 
+    let explicit_format = separator.is_some() || terminator.is_some();
+
     let sep = match separator {
         Some(Value {
             value: UntaggedValue::Primitive(Primitive::String(s)),
@@ -136,11 +138,18 @@ async fn seq(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStre
         _ => sep,
     };
 
-    run_seq(sep.to_string(), Some(term.to_string()), widths, rest)
+    run_seq(
+        sep.to_string(),
+        Some(term.to_string()),
+        widths,
+        rest,
+        explicit_format,
+    )
 }
 
 #[cfg(test)]
 mod tests {
+    use super::seq_count;
     use super::Seq;
     use super::ShellError;
 
@@ -150,6 +159,23 @@ mod tests {
 
         Ok(test_examples(Seq {})?)
     }
+
+    #[test]
+    fn counts_fractional_step_without_dropping_the_endpoint() {
+        assert_eq!(seq_count(0.1, 0.1, 0.3), 3);
+    }
+
+    #[test]
+    fn counts_negative_steps() {
+        assert_eq!(seq_count(5.0, -1.0, 1.0), 5);
+        assert_eq!(seq_count(5.0, -2.0, 0.0), 3);
+    }
+
+    #[test]
+    fn counts_single_element_ranges() {
+        assert_eq!(seq_count(3.0, 1.0, 3.0), 1);
+        assert_eq!(seq_count(3.0, -1.0, 3.0), 1);
+    }
 }
 
 // #[derive(Clone)]
@@ -181,6 +207,7 @@ pub fn run_seq(
     termy: Option<String>,
     widths: bool,
     rest: Vec<Value>,
+    explicit_format: bool,
 ) -> Result<OutputStream, ShellError> {
     let free: Vec<String> = rest
         .iter()
@@ -263,16 +290,31 @@ pub fn run_seq(
         terminator,
         widths,
         padding,
+        explicit_format,
     )
 
     // Ok(0)
 }
 
-fn done_printing(next: f64, step: f64, last: f64) -> bool {
-    if step >= 0f64 {
-        next > last
+/// Deterministic element count for the `first..=last` range stepping by
+/// `step`, instead of recomputing `value` each iteration and comparing
+/// against `last` with `done_printing` — that approach accumulates float
+/// error across iterations, so e.g. `seq 0.1 0.1 0.3` can drop or add a
+/// final element depending on rounding. A small tolerance (scaled to
+/// `last`'s magnitude) absorbs the same class of error in one place, up
+/// front, so endpoint inclusion matches GNU `seq` on fractional steps.
+fn seq_count(first: f64, step: f64, last: f64) -> isize {
+    if step == 0.0 {
+        return 0;
+    }
+
+    let epsilon = 1e-9 * f64::max(1.0, last.abs());
+    let n = ((last - first) / step + epsilon).floor() as isize + 1;
+
+    if n <= 0 {
+        0
     } else {
-        next < last
+        n
     }
 }
 
@@ -286,33 +328,56 @@ fn print_seq(
     terminator: String,
     pad: bool,
     padding: usize,
+    explicit_format: bool,
 ) -> Result<OutputStream, ShellError> {
-    let mut i = 0isize;
-    let mut value = first + i as f64 * step;
+    if !explicit_format {
+        return Ok(stream_seq(first, step, last, largest_dec));
+    }
+
+    let n = seq_count(first, step, last);
     let mut ret_str = "".to_owned();
-    while !done_printing(value, step, last) {
+    for i in 0..n {
+        let value = first + i as f64 * step;
         let istr = format!("{:.*}", largest_dec, value);
         let ilen = istr.len();
         let before_dec = istr.find('.').unwrap_or(ilen);
         if pad && before_dec < padding {
             for _ in 0..(padding - before_dec) {
-                // print!("0");
                 ret_str.push_str("0");
             }
         }
-        // print!("{}", istr);
         ret_str.push_str(&istr);
-        i += 1;
-        value = first + i as f64 * step;
-        if !done_printing(value, step, last) {
-            // print!("{}", separator);
+        if i < n - 1 {
             ret_str.push_str(&separator);
         }
     }
-    if (first >= last && step < 0f64) || (first <= last && step > 0f64) {
-        // print!("{}", terminator);
+    if n > 0 {
         ret_str.push_str(&terminator);
     }
 
     Ok(OutputStream::one(ReturnSuccess::value(ret_str)))
 }
+
+/// Streams each generated number as its own `Value`, so downstream commands
+/// like `sum`/`where`/`each` can operate on individual rows instead of
+/// parsing one joined string. Keeps integer output (`seq 1 5 | sum` => `15`)
+/// whenever `first`/`step`/`last` were all given without a fractional part,
+/// i.e. `largest_dec` never grew beyond zero.
+fn stream_seq(first: f64, step: f64, last: f64, largest_dec: usize) -> OutputStream {
+    let integral = largest_dec == 0;
+    let n = seq_count(first, step, last);
+
+    let rows: Vec<_> = (0..n)
+        .map(|i| {
+            let value = first + i as f64 * step;
+            let untagged = if integral {
+                UntaggedValue::int(value.round() as i64)
+            } else {
+                UntaggedValue::decimal_from_float(value, Tag::unknown().span)
+            };
+            ReturnSuccess::value(untagged.into_value(Tag::unknown()))
+        })
+        .collect();
+
+    futures::stream::iter(rows).to_output_stream()
+}
@@ -0,0 +1,120 @@
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "str distance"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str distance")
+            .required(
+                "target",
+                SyntaxShape::String,
+                "the string to compare each input string against",
+            )
+            .switch(
+                "normalized",
+                "return a 0.0-1.0 similarity instead of the raw edit distance",
+                None,
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Computes the Levenshtein edit distance between each input string and a target"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let registry = registry.clone();
+        let args = args.evaluate_once(&registry).await?;
+        let name_tag = args.name_tag();
+        let normalized = args.has("normalized");
+        let target: String = args.req(0)?;
+
+        let (input, _) = args.parts();
+
+        Ok(input
+            .map(move |value| action(value, &target, normalized, &name_tag))
+            .to_output_stream())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Compute the edit distance between two strings",
+                example: "echo 'kitten' | str distance 'sitting'",
+                result: Some(vec![UntaggedValue::int(3).into()]),
+            },
+            Example {
+                description: "Compute a normalized similarity instead",
+                example: "echo 'kitten' | str distance 'sitting' --normalized",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn action(value: Value, target: &str, normalized: bool, name_tag: &Tag) -> ReturnValue {
+    let tag = value.tag.clone();
+
+    let source = match &value.value {
+        UntaggedValue::Primitive(Primitive::String(s)) => s.clone(),
+        UntaggedValue::Primitive(Primitive::Line(s)) => s.clone(),
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Expected a string",
+                "str distance only works on strings",
+                name_tag,
+            ))
+        }
+    };
+
+    let distance = levenshtein_distance(&source, target);
+
+    let out = if normalized {
+        let max_len = source.chars().count().max(target.chars().count());
+        let similarity = if max_len == 0 {
+            1.0
+        } else {
+            1.0 - (distance as f64 / max_len as f64)
+        };
+        UntaggedValue::decimal_from_float(similarity, tag.span)
+    } else {
+        UntaggedValue::int(distance as i64)
+    };
+
+    ReturnSuccess::value(out.into_value(tag))
+}
+
+/// Classic two-row Levenshtein DP over Unicode scalar values: `prev`/`curr`
+/// hold the edit distances for the row above and the row being built, so the
+/// whole computation stays O(len_b) in memory instead of the
+/// O(len_a * len_b) a full matrix would need.
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let b_chars: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b_chars.len()).collect();
+    let mut curr = vec![0; b_chars.len() + 1];
+
+    for (i, a_char) in a.chars().enumerate() {
+        curr[0] = i + 1;
+
+        for (j, b_char) in b_chars.iter().enumerate() {
+            let cost = if a_char == *b_char { 0 } else { 1 };
+            curr[j + 1] = (prev[j + 1] + 1).min(curr[j] + 1).min(prev[j] + cost);
+        }
+
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[b_chars.len()]
+}
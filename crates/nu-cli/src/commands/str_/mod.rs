@@ -1,6 +1,7 @@
 mod capitalize;
 mod collect;
 mod command;
+mod distance;
 mod downcase;
 mod find_replace;
 mod from;
@@ -20,6 +21,7 @@ mod upcase;
 pub use capitalize::SubCommand as StrCapitalize;
 pub use collect::SubCommand as StrCollect;
 pub use command::Command as Str;
+pub use distance::SubCommand as StrDistance;
 pub use downcase::SubCommand as StrDowncase;
 pub use find_replace::SubCommand as StrFindReplace;
 pub use from::SubCommand as StrFrom;
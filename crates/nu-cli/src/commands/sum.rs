@@ -9,6 +9,7 @@ use indexmap::map::{IndexMap};
 
 pub struct Sum;
 
+#[async_trait]
 impl WholeStreamCommand for Sum {
     fn name(&self) -> &str {
         "sum"
@@ -22,7 +23,7 @@ impl WholeStreamCommand for Sum {
         "Sums the values."
     }
 
-    fn run(
+    async fn run(
         &self,
         args: CommandArgs,
         registry: &CommandRegistry,
@@ -34,6 +35,7 @@ impl WholeStreamCommand for Sum {
             host: args.host,
             ctrl_c: args.ctrl_c,
             name: args.call_info.name_tag,
+            current_errors: args.current_errors,
         })
     }
 
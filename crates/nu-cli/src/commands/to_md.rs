@@ -3,13 +3,16 @@ use crate::prelude::*;
 use futures::StreamExt;
 use nu_data::value::format_leaf;
 use nu_errors::ShellError;
-use nu_protocol::{ReturnSuccess, Signature, UntaggedValue, Value};
+use nu_protocol::{Primitive, ReturnSuccess, Signature, UntaggedValue, Value};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 pub struct ToMarkdown;
 
 #[derive(Deserialize)]
 pub struct ToMarkdownArgs {
     pretty: bool,
+    per_element: bool,
 }
 
 #[async_trait]
@@ -19,11 +22,18 @@ impl WholeStreamCommand for ToMarkdown {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("to md").switch(
-            "pretty",
-            "Formats the Markdown table to vertically align items",
-            Some('p'),
-        )
+        Signature::build("to md")
+            .switch(
+                "pretty",
+                "Formats the Markdown table to vertically align items",
+                Some('p'),
+            )
+            .switch(
+                "per-element",
+                "Renders each value as its own Markdown construct (paragraph, heading, \
+                 bullet list or table) instead of one flat table",
+                Some('e'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -50,6 +60,11 @@ impl WholeStreamCommand for ToMarkdown {
                 example: "ls | to md -p",
                 result: None,
             },
+            Example {
+                description: "Renders each value as its own Markdown construct",
+                example: "open doc.json | to md -e",
+                result: None,
+            },
         ]
     }
 }
@@ -57,9 +72,73 @@ impl WholeStreamCommand for ToMarkdown {
 async fn to_md(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
     let registry = registry.clone();
     let name_tag = args.call_info.name_tag.clone();
-    let (ToMarkdownArgs { pretty }, input) = args.process(&registry).await?;
+    let (ToMarkdownArgs { pretty, per_element }, input) = args.process(&registry).await?;
     let input: Vec<Value> = input.collect().await;
-    let headers = nu_protocol::merge_descriptors(&input);
+
+    let output_string = if per_element {
+        input
+            .iter()
+            .map(|value| render_element(value, pretty))
+            .collect::<Vec<String>>()
+            .join("\n\n")
+    } else {
+        render_table(&input, pretty)
+    };
+
+    Ok(OutputStream::one(ReturnSuccess::value(
+        UntaggedValue::string(output_string).into_value(name_tag),
+    )))
+}
+
+/// Renders a single value as a standalone Markdown construct, for `to md
+/// --per-element`: a bare string becomes a paragraph, a single-column row
+/// whose column name looks like a heading (`title`, `h1`..`h6`) becomes an
+/// ATX heading, a table of non-row values becomes a bullet list, and
+/// anything else still falls back to `render_table`'s pipe-delimited table.
+fn render_element(value: &Value, pretty: bool) -> String {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::String(s)) | UntaggedValue::Primitive(Primitive::Line(s)) => {
+            s.clone()
+        }
+        UntaggedValue::Row(row) if row.entries.len() == 1 => {
+            let (column, data) = row.entries.iter().next().expect("just checked len == 1");
+
+            match heading_level(column) {
+                Some(level) => format!(
+                    "{} {}",
+                    "#".repeat(level),
+                    format_leaf(data).plain_string(100_000)
+                ),
+                None => render_table(std::slice::from_ref(value), pretty),
+            }
+        }
+        UntaggedValue::Table(rows) if rows.iter().all(|v| !matches!(&v.value, UntaggedValue::Row(_))) => {
+            rows.iter()
+                .map(|v| format!("- {}", format_leaf(&v.value).plain_string(100_000)))
+                .collect::<Vec<String>>()
+                .join("\n")
+        }
+        UntaggedValue::Table(rows) => render_table(rows, pretty),
+        _ => format_leaf(&value.value).plain_string(100_000),
+    }
+}
+
+/// `h1`..`h6` or `title`/`heading` name a single-column row as an ATX
+/// heading; the digit (or 1, for the bare names) is the heading level.
+fn heading_level(column: &str) -> Option<usize> {
+    match column.to_lowercase().as_str() {
+        "title" | "heading" | "h1" => Some(1),
+        "h2" => Some(2),
+        "h3" => Some(3),
+        "h4" => Some(4),
+        "h5" => Some(5),
+        "h6" => Some(6),
+        _ => None,
+    }
+}
+
+fn render_table(input: &[Value], pretty: bool) -> String {
+    let headers = nu_protocol::merge_descriptors(input);
 
     let mut escaped_headers: Vec<String> = Vec::new();
     let mut column_width_vector: Vec<usize> = Vec::new();
@@ -67,21 +146,21 @@ async fn to_md(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputSt
     if !headers.is_empty() && (headers.len() > 1 || headers[0] != "") {
         for header in &headers {
             let escaped_header_string = htmlescape::encode_minimal(&header);
-            column_width_vector.push(escaped_header_string.len());
+            column_width_vector.push(display_width(&escaped_header_string));
             escaped_headers.push(escaped_header_string);
         }
     }
 
     let mut escaped_rows: Vec<Vec<String>> = Vec::new();
 
-    for row in &input {
+    for row in input {
         if let UntaggedValue::Row(row) = row.value.clone() {
             let mut escaped_row_vec: Vec<String> = Vec::new();
 
             for i in 0..headers.len() {
                 let data = row.get_data(&headers[i]);
                 let value_string = format_leaf(data.borrow()).plain_string(100_000);
-                let new_column_width = value_string.len();
+                let new_column_width = display_width(&value_string);
                 escaped_row_vec.push(value_string);
 
                 if column_width_vector[i] < new_column_width {
@@ -93,16 +172,12 @@ async fn to_md(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputSt
         }
     }
 
-    let output_string = get_output_string(
+    get_output_string(
         &escaped_headers,
         &escaped_rows,
         &column_width_vector,
         pretty,
-    );
-
-    Ok(OutputStream::one(ReturnSuccess::value(
-        UntaggedValue::string(output_string).into_value(name_tag),
-    )))
+    )
 }
 
 fn get_output_string(
@@ -180,15 +255,26 @@ fn get_output_string(
 }
 
 fn get_padded_string(text: String, desired_length: usize, padding_character: char) -> String {
+    let padding = desired_length.saturating_sub(display_width(&text));
+
     format!(
         "{}{}",
         text,
-        padding_character
-            .to_string()
-            .repeat(desired_length - text.len())
+        padding_character.to_string().repeat(padding)
     )
 }
 
+/// Terminal display width of `text`: wide CJK characters count as 2 columns,
+/// zero-width combining marks count as 0, so a grapheme cluster like "é"
+/// (e + combining acute) doesn't get double-counted. This is what
+/// `column_width_vector`/`get_padded_string` align on instead of byte count,
+/// which `String::len()` would otherwise report for multi-byte UTF-8 text.
+fn display_width(text: &str) -> usize {
+    text.graphemes(true)
+        .map(|grapheme| UnicodeWidthStr::width(grapheme))
+        .sum()
+}
+
 #[cfg(test)]
 mod tests {
     use super::ShellError;
@@ -2,6 +2,7 @@ use crate::commands::WholeStreamCommand;
 use crate::prelude::*;
 use nu_errors::{CoerceInto, ShellError};
 use nu_protocol::{Primitive, ReturnSuccess, Signature, UnspannedPathMember, UntaggedValue, Value};
+use nu_source::{Span, Tag};
 
 pub struct ToTOML;
 
@@ -39,8 +40,12 @@ impl WholeStreamCommand for ToTOML {
     // }
 }
 
-/// Converts a nu_protocol::Value into a toml::Value 
+/// Converts a nu_protocol::Value into a toml::Value
 /// Will return a Shell Error, if the Nu Value is not a valid top-level TOML Value
+///
+/// `Primitive::Date` is emitted as a native `toml::Value::Datetime` (built from
+/// its RFC3339 representation) rather than a plain string, so dates round-trip
+/// losslessly through `open foo.toml | to toml` instead of coming back quoted.
 pub fn value_to_toml_value(v: &Value) -> Result<toml::Value, ShellError> {
     // Helper method to recursively convert nu_protocol::Value -> toml::Value
     fn helper(v: &Value) -> Result<toml::Value, ShellError> {
@@ -48,7 +53,15 @@ pub fn value_to_toml_value(v: &Value) -> Result<toml::Value, ShellError> {
             UntaggedValue::Primitive(Primitive::Boolean(b)) => toml::Value::Boolean(*b),
             UntaggedValue::Primitive(Primitive::Bytes(b)) => toml::Value::Integer(*b as i64),
             UntaggedValue::Primitive(Primitive::Duration(d)) => toml::Value::Integer(*d as i64),
-            UntaggedValue::Primitive(Primitive::Date(d)) => toml::Value::String(d.to_string()),
+            UntaggedValue::Primitive(Primitive::Date(d)) => toml::Value::Datetime(
+                d.to_rfc3339().parse().map_err(|_| {
+                    ShellError::labeled_error(
+                        "Could not convert date to a TOML datetime",
+                        "invalid date",
+                        &v.tag,
+                    )
+                })?,
+            ),
             UntaggedValue::Primitive(Primitive::EndOfStream) => {
                 toml::Value::String("<End of Stream>".to_string())
             }
@@ -111,11 +124,11 @@ pub fn value_to_toml_value(v: &Value) -> Result<toml::Value, ShellError> {
         }
         UntaggedValue::Primitive(Primitive::String(s)) => {
             // Attempt to de-serialize the String
-            toml::de::from_str(s).map_err(|_| {
+            toml::de::from_str(s).map_err(|err| {
                 ShellError::labeled_error(
                     format!("{:?} unable to de-serialize string to TOML", s),
                     "invalid TOML",
-                    v.tag(),
+                    toml_error_span(s, &v.tag, &err),
                 )
             })
         }
@@ -127,6 +140,72 @@ pub fn value_to_toml_value(v: &Value) -> Result<toml::Value, ShellError> {
     }
 }
 
+/// The TOML serializer rejects any table where a scalar key is emitted after a
+/// nested table or array-of-tables (the "values must be emitted before tables"
+/// rule). `value_to_toml_value` builds its map in nu `Row` order, so a row such
+/// as `{a: {x: 1}, b: 2}` would otherwise fail to serialize even though TOML
+/// can represent it fine. Stably partition every table's entries into
+/// scalars/arrays-of-scalars first, then tables/arrays-of-tables, recursing
+/// into nested tables and array-of-tables elements, before handing the value
+/// to `toml::to_string`.
+fn is_toml_table_like(value: &toml::Value) -> bool {
+    match value {
+        toml::Value::Table(_) => true,
+        toml::Value::Array(items) => {
+            !items.is_empty() && items.iter().all(|item| matches!(item, toml::Value::Table(_)))
+        }
+        _ => false,
+    }
+}
+
+fn reorder_toml_value(value: toml::Value) -> toml::Value {
+    match value {
+        toml::Value::Table(m) => {
+            let (tables, scalars): (Vec<_>, Vec<_>) =
+                m.into_iter().partition(|(_, v)| is_toml_table_like(v));
+
+            let mut reordered = toml::map::Map::new();
+            for (k, v) in scalars {
+                reordered.insert(k, reorder_toml_value(v));
+            }
+            for (k, v) in tables {
+                reordered.insert(k, reorder_toml_value(v));
+            }
+            toml::Value::Table(reordered)
+        }
+        toml::Value::Array(items) => {
+            toml::Value::Array(items.into_iter().map(reorder_toml_value).collect())
+        }
+        other => other,
+    }
+}
+
+/// Turns a `toml::de::Error`'s line/column into a `Span` over just the
+/// offending token within `source`, anchored at `tag`'s start, so a bad key or
+/// value inside a large embedded TOML blob gets pointed at directly instead of
+/// blaming the whole string.
+fn toml_error_span(source: &str, tag: &Tag, err: &toml::de::Error) -> Span {
+    let offset = match err.line_col() {
+        Some((line, col)) => {
+            let line_start: usize = source
+                .split('\n')
+                .take(line)
+                .map(|l| l.len() + 1)
+                .sum();
+            line_start + col
+        }
+        None => 0,
+    };
+
+    let start = tag.span.start() + offset.min(source.len());
+    let end = source[offset.min(source.len())..]
+        .find(|c: char| c.is_whitespace())
+        .map(|rel| start + rel)
+        .unwrap_or_else(|| tag.span.end());
+
+    Span::new(start, end.max(start + 1).min(tag.span.end()))
+}
+
 fn collect_values(input: &[Value]) -> Result<Vec<toml::Value>, ShellError> {
     let mut out = vec![];
 
@@ -158,7 +237,7 @@ fn to_toml(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream
             let value_span = value.tag.span;
             match value_to_toml_value(&value) {
                 Ok(toml_value) => {
-                    match toml::to_string(&toml_value) {
+                    match toml::to_string(&reorder_toml_value(toml_value)) {
                         Ok(x) => yield ReturnSuccess::value(
                             UntaggedValue::Primitive(Primitive::String(x)).into_value(&name_tag),
                         ),
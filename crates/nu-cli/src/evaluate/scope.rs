@@ -3,6 +3,43 @@ use crate::prelude::*;
 use nu_parser::ParserScope;
 use nu_protocol::Value;
 use nu_source::Spanned;
+use std::collections::HashSet;
+
+/// A per-frame restriction on which commands are visible to blocks evaluated
+/// inside that frame, used to run untrusted scripts or plugins with a reduced
+/// surface (e.g. read-only, no external process spawning).
+///
+/// A frame with no `Capabilities` is unrestricted. Restrictions only ever
+/// narrow visibility as scopes nest: see [`Scope::get_command`].
+#[derive(Debug, Clone, Default)]
+pub struct Capabilities {
+    /// If set, only these command names are visible; everything else is denied.
+    pub allowed_names: Option<HashSet<String>>,
+    /// Command names that are denied outright, regardless of `allowed_names`.
+    pub denied_names: HashSet<String>,
+    /// Coarse capability tags (see [`crate::commands::WholeStreamCommand::capabilities`])
+    /// that are denied; a command is blocked if any of its tags appear here.
+    pub denied_tags: HashSet<String>,
+}
+
+impl Capabilities {
+    pub fn permits(&self, name: &str, command: &Command) -> bool {
+        if let Some(allowed) = &self.allowed_names {
+            if !allowed.contains(name) {
+                return false;
+            }
+        }
+
+        if self.denied_names.contains(name) {
+            return false;
+        }
+
+        !command
+            .capabilities()
+            .iter()
+            .any(|tag| self.denied_tags.contains(*tag))
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct Scope {
@@ -16,15 +53,41 @@ impl Scope {
         }
     }
     pub fn get_command(&self, name: &str) -> Option<Command> {
-        for frame in self.frames.lock().iter().rev() {
+        let frames = self.frames.lock();
+        // Capability restrictions accumulate as we walk outward from the
+        // innermost frame, so a restriction introduced by an inner frame
+        // still applies even if an outer frame turns out to define the
+        // command — restrictions can only narrow, never be widened by
+        // continuing to look further out.
+        let mut restrictions = vec![];
+
+        for frame in frames.iter().rev() {
+            if let Some(capabilities) = &frame.capabilities {
+                restrictions.push(capabilities);
+            }
+
             if let Some(command) = frame.get_command(name) {
-                return Some(command);
+                if restrictions
+                    .iter()
+                    .all(|capabilities| capabilities.permits(name, &command))
+                {
+                    return Some(command);
+                }
+
+                return None;
             }
         }
 
         None
     }
 
+    /// Enter a new scope frame whose command visibility is restricted to
+    /// `capabilities`, for evaluating a block under reduced authority (e.g.
+    /// a sandboxed plugin or untrusted script).
+    pub fn enter_scope_with_capabilities(&self, capabilities: Capabilities) {
+        self.frames.lock().push(ScopeFrame::restricted(capabilities));
+    }
+
     pub fn add_command(&self, name: String, command: Command) {
         // Note: this is assumed to always be true, as there is always a global top frame
         if let Some(frame) = self.frames.lock().last_mut() {
@@ -46,14 +109,27 @@ impl Scope {
         names
     }
 
-    pub fn has_command(&self, name: &str) -> bool {
-        for frame in self.frames.lock().iter() {
-            if frame.has_command(name) {
-                return true;
+    /// Every command name visible from this scope, paired with the depth of
+    /// the frame it's defined in (`0` is the outermost/global frame), so
+    /// shadowing between frames is visible to callers like the `scope`
+    /// introspection command.
+    pub fn get_command_names_with_depth(&self) -> Vec<(String, usize)> {
+        let mut by_name = IndexMap::new();
+
+        for (depth, frame) in self.frames.lock().iter().enumerate() {
+            for name in frame.get_command_names() {
+                by_name.insert(name, depth);
             }
         }
 
-        false
+        let mut names: Vec<(String, usize)> = by_name.into_iter().collect();
+        names.retain(|(name, _)| self.get_command(name).is_some());
+        names.sort_by(|a, b| a.0.cmp(&b.0));
+        names
+    }
+
+    pub fn has_command(&self, name: &str) -> bool {
+        self.get_command(name).is_some()
     }
 
     pub fn expect_command(&self, name: &str) -> Result<Command, ShellError> {
@@ -93,6 +169,72 @@ impl Scope {
         output
     }
 
+    /// Every variable visible from this scope, paired with the depth of the
+    /// frame it's bound in (`0` is the outermost/global frame).
+    pub fn get_vars_with_depth(&self) -> Vec<(String, Value, usize)> {
+        let mut by_name = IndexMap::new();
+
+        for (depth, frame) in self.frames.lock().iter().enumerate() {
+            for (name, value) in frame.vars.iter() {
+                by_name.insert(name.clone(), (value.clone(), depth));
+            }
+        }
+
+        by_name
+            .into_iter()
+            .map(|(name, (value, depth))| (name, value, depth))
+            .collect()
+    }
+
+    /// Every environment variable visible from this scope, paired with the
+    /// depth of the frame it's bound in.
+    pub fn get_env_vars_with_depth(&self) -> Vec<(String, String, usize)> {
+        let mut by_name = IndexMap::new();
+
+        for (depth, frame) in self.frames.lock().iter().enumerate() {
+            for (name, value) in frame.env.iter() {
+                by_name.insert(name.clone(), (value.clone(), depth));
+            }
+        }
+
+        by_name
+            .into_iter()
+            .map(|(name, (value, depth))| (name, value, depth))
+            .collect()
+    }
+
+    /// Every alias visible from this scope, paired with the depth of the
+    /// frame it's defined in.
+    pub fn get_aliases_with_depth(&self) -> Vec<(String, Vec<Spanned<String>>, usize)> {
+        let mut by_name = IndexMap::new();
+
+        for (depth, frame) in self.frames.lock().iter().enumerate() {
+            for (name, replacement) in frame.aliases.iter() {
+                by_name.insert(name.clone(), (replacement.clone(), depth));
+            }
+        }
+
+        by_name
+            .into_iter()
+            .map(|(name, (replacement, depth))| (name, replacement, depth))
+            .collect()
+    }
+
+    /// The number of frames currently on the scope stack (the global frame
+    /// plus one per `enter_scope()`/`enter_scope_with_capabilities()`).
+    pub fn frame_count(&self) -> usize {
+        self.frames.lock().len()
+    }
+
+    /// Whether the frame at `depth` restricts command visibility.
+    pub fn frame_is_restricted(&self, depth: usize) -> bool {
+        self.frames
+            .lock()
+            .get(depth)
+            .map(|frame| frame.capabilities.is_some())
+            .unwrap_or(false)
+    }
+
     pub fn get_var(&self, name: &str) -> Option<Value> {
         for frame in self.frames.lock().iter().rev() {
             if let Some(v) = frame.vars.get(name) {
@@ -169,6 +311,7 @@ pub struct ScopeFrame {
     pub env: IndexMap<String, String>,
     pub commands: IndexMap<String, Command>,
     pub aliases: IndexMap<String, Vec<Spanned<String>>>,
+    pub capabilities: Option<Capabilities>,
 }
 
 impl ScopeFrame {
@@ -194,6 +337,16 @@ impl ScopeFrame {
             env: IndexMap::new(),
             commands: IndexMap::new(),
             aliases: IndexMap::new(),
+            capabilities: None,
+        }
+    }
+
+    /// A frame with no commands/vars of its own, used solely to narrow
+    /// command visibility for the block evaluated inside it.
+    pub fn restricted(capabilities: Capabilities) -> ScopeFrame {
+        ScopeFrame {
+            capabilities: Some(capabilities),
+            ..ScopeFrame::new()
         }
     }
 }
@@ -0,0 +1,226 @@
+use crate::commands::{whole_stream_command, Command, CommandArgs, WholeStreamCommand};
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{CallInfo, ReturnValue, Signature, Value};
+use nu_source::Tag;
+use serde::{Deserialize, Serialize};
+use std::ffi::OsStr;
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Child, ChildStdin, ChildStdout, Command as Process, Stdio};
+
+/// The newline-delimited JSON-RPC envelope spoken with a `nu_plugin_*`
+/// sidecar over its stdin/stdout. `UnevaluatedCallInfo`, `CallInfo` and
+/// `Value` already derive `Serialize`/`Deserialize`, so the wire types are
+/// mostly just these method/params pairs rather than a bespoke protocol.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(tag = "method", content = "params", rename_all = "snake_case")]
+enum PluginRequest {
+    Config,
+    BeginFilter(CallInfo),
+    Filter(Value),
+    EndFilter,
+    Sink(CallInfo, Vec<Value>),
+    Quit,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(tag = "method", content = "params", rename_all = "snake_case")]
+enum PluginResponse {
+    Config(Signature),
+    Values(Vec<ReturnValue>),
+    Ack,
+}
+
+/// Finds every `nu_plugin_*` (or `nu_plugin_*.exe` on Windows) executable on
+/// the given search paths (typically `$PATH` plus a dedicated plugin dir).
+pub fn discover_plugins(search_paths: &[PathBuf]) -> Vec<PathBuf> {
+    let mut found = vec![];
+
+    for dir in search_paths {
+        let entries = match std::fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let stem = path.file_stem().and_then(OsStr::to_str).unwrap_or("");
+
+            if !stem.starts_with("nu_plugin_") {
+                continue;
+            }
+
+            #[cfg(windows)]
+            let is_executable = path.extension().and_then(OsStr::to_str) == Some("exe");
+            #[cfg(not(windows))]
+            let is_executable = path.is_file();
+
+            if is_executable {
+                found.push(path);
+            }
+        }
+    }
+
+    found
+}
+
+/// Spawns every plugin `discover_plugins` finds, handshakes with a `Config`
+/// request to fetch its `Signature`, and returns one synthesized `Command`
+/// per plugin, ready to feed into `CommandRegistry`/`Scope::add_command`
+/// alongside the in-process `WholeStreamCommand`s.
+pub fn load_plugins(search_paths: &[PathBuf]) -> Vec<Arc<Command>> {
+    discover_plugins(search_paths)
+        .into_iter()
+        .filter_map(|path| match PluginCommand::spawn(path.clone()) {
+            Ok(plugin) => Some(whole_stream_command(plugin)),
+            Err(err) => {
+                eprintln!("Could not load plugin {}: {}", path.display(), err);
+                None
+            }
+        })
+        .collect()
+}
+
+struct PluginProcess {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+}
+
+impl PluginProcess {
+    fn spawn(path: &Path) -> Result<PluginProcess, ShellError> {
+        let mut child = Process::new(path)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()
+            .map_err(|e| {
+                ShellError::labeled_error(
+                    "Could not start plugin",
+                    format!("{}", e),
+                    Tag::unknown(),
+                )
+            })?;
+
+        let stdin = child.stdin.take().ok_or_else(|| {
+            ShellError::unexpected("Plugin process did not expose a stdin handle")
+        })?;
+        let stdout = child.stdout.take().ok_or_else(|| {
+            ShellError::unexpected("Plugin process did not expose a stdout handle")
+        })?;
+
+        Ok(PluginProcess {
+            child,
+            stdin,
+            stdout: BufReader::new(stdout),
+        })
+    }
+
+    fn call(&mut self, request: &PluginRequest) -> Result<PluginResponse, ShellError> {
+        let mut line = serde_json::to_string(request)
+            .map_err(|e| ShellError::unexpected(format!("{}", e)))?;
+        line.push('\n');
+
+        self.stdin
+            .write_all(line.as_bytes())
+            .map_err(|e| ShellError::unexpected(format!("plugin write failed: {}", e)))?;
+        self.stdin
+            .flush()
+            .map_err(|e| ShellError::unexpected(format!("plugin write failed: {}", e)))?;
+
+        let mut response = String::new();
+        self.stdout
+            .read_line(&mut response)
+            .map_err(|e| ShellError::unexpected(format!("plugin read failed: {}", e)))?;
+
+        serde_json::from_str(&response).map_err(|e| {
+            ShellError::labeled_error(
+                "Malformed plugin response",
+                format!("{}", e),
+                Tag::unknown(),
+            )
+        })
+    }
+}
+
+impl Drop for PluginProcess {
+    fn drop(&mut self) {
+        let _ = self.call(&PluginRequest::Quit);
+        let _ = self.child.kill();
+    }
+}
+
+/// A `WholeStreamCommand` that forwards its `CallInfo` and `InputStream` to
+/// a plugin process spawned for each run instead of running in-process, mapping the
+/// `begin_filter`/`filter`/`end_filter` RPC round trip back into a regular
+/// `OutputStream`.
+pub struct PluginCommand {
+    path: PathBuf,
+    signature: Signature,
+}
+
+impl PluginCommand {
+    fn spawn(path: PathBuf) -> Result<PluginCommand, ShellError> {
+        let mut process = PluginProcess::spawn(&path)?;
+
+        let signature = match process.call(&PluginRequest::Config)? {
+            PluginResponse::Config(signature) => signature,
+            _ => {
+                return Err(ShellError::unexpected(
+                    "Plugin did not respond to `config` with a signature",
+                ))
+            }
+        };
+
+        Ok(PluginCommand { path, signature })
+    }
+}
+
+#[async_trait]
+impl WholeStreamCommand for PluginCommand {
+    fn name(&self) -> &str {
+        &self.signature.name
+    }
+
+    fn signature(&self) -> Signature {
+        self.signature.clone()
+    }
+
+    fn usage(&self) -> &str {
+        &self.signature.usage
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        _registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let call_info = args.call_info.evaluate(_registry).await?;
+        let mut input = args.input;
+        let path = self.path.clone();
+
+        let mut process = PluginProcess::spawn(&path)?;
+
+        match process.call(&PluginRequest::BeginFilter(call_info.clone()))? {
+            PluginResponse::Ack => {}
+            _ => return Err(ShellError::unexpected("Plugin rejected `begin_filter`")),
+        }
+
+        let mut results = vec![];
+
+        while let Some(value) = input.next().await {
+            match process.call(&PluginRequest::Filter(value))? {
+                PluginResponse::Values(values) => results.extend(values),
+                _ => return Err(ShellError::unexpected("Plugin rejected `filter`")),
+            }
+        }
+
+        if let PluginResponse::Values(values) = process.call(&PluginRequest::EndFilter)? {
+            results.extend(values);
+        }
+
+        Ok(futures::stream::iter(results).to_output_stream())
+    }
+}
@@ -1,15 +1,66 @@
 use ansi_term::{Color, Style};
 use nu_protocol::hir::FlatShape;
 use nu_source::{Span, Spanned};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json;
 use std::error::Error;
-use std::{fmt, io};
+use std::{env, fmt, io};
 
 pub trait Palette {
     fn styles_for_shape(&self, shape: &Spanned<FlatShape>) -> Vec<Spanned<Style>>;
 }
 
+/// Wraps a `Palette` and suppresses its styling (emitting `Style::default()`
+/// spans instead) according to the clicolors convention: forced on by
+/// `CLICOLOR_FORCE`, forced off by `NO_COLOR`/`CLICOLOR=0` or a non-TTY
+/// output stream, otherwise left to the wrapped palette.
+pub struct ColorGated<P: Palette> {
+    inner: P,
+    enabled: bool,
+}
+
+impl<P: Palette> ColorGated<P> {
+    pub fn new(inner: P) -> ColorGated<P> {
+        ColorGated {
+            enabled: Self::should_style(),
+            inner,
+        }
+    }
+
+    fn should_style() -> bool {
+        if env::var_os("CLICOLOR_FORCE").map_or(false, |v| v != "0") {
+            return true;
+        }
+
+        if env::var_os("NO_COLOR").is_some() {
+            return false;
+        }
+
+        if env::var_os("CLICOLOR").map_or(false, |v| v == "0") {
+            return false;
+        }
+
+        atty::is(atty::Stream::Stdout)
+    }
+}
+
+impl<P: Palette> Palette for ColorGated<P> {
+    fn styles_for_shape(&self, shape: &Spanned<FlatShape>) -> Vec<Spanned<Style>> {
+        let spans = self.inner.styles_for_shape(shape);
+        if self.enabled {
+            spans
+        } else {
+            spans
+                .into_iter()
+                .map(|span| Spanned {
+                    item: Style::default(),
+                    span: span.span,
+                })
+                .collect()
+        }
+    }
+}
+
 pub struct DefaultPalette {}
 
 impl Palette for DefaultPalette {
@@ -71,64 +122,76 @@ impl ThemedPallet {
         let theme = serde_json::from_reader(reader)?;
         Ok(ThemedPallet { theme })
     }
+
+    pub fn to_writer<W: io::Write>(&self, writer: &mut W) -> Result<(), ThemeError> {
+        serde_json::to_writer_pretty(writer, &self.theme)?;
+        Ok(())
+    }
 }
 
 impl Palette for ThemedPallet {
     fn styles_for_shape(&self, shape: &Spanned<FlatShape>) -> Vec<Spanned<Style>> {
         match &shape.item {
             FlatShape::OpenDelimiter(_) => {
-                single_style_span(self.theme.open_delimiter.normal(), shape.span)
+                single_style_span(self.theme.open_delimiter.to_style(), shape.span)
             }
             FlatShape::CloseDelimiter(_) => {
-                single_style_span(self.theme.close_delimiter.normal(), shape.span)
+                single_style_span(self.theme.close_delimiter.to_style(), shape.span)
+            }
+            FlatShape::ItVariable => {
+                single_style_span(self.theme.it_variable.to_style(), shape.span)
             }
-            FlatShape::ItVariable => single_style_span(self.theme.it_variable.bold(), shape.span),
-            FlatShape::Keyword => single_style_span(self.theme.keyword.bold(), shape.span),
-            FlatShape::Variable => single_style_span(self.theme.variable.normal(), shape.span),
-            FlatShape::Identifier => single_style_span(self.theme.identifier.normal(), shape.span),
-            FlatShape::Type => single_style_span(self.theme.r#type.bold(), shape.span),
-            FlatShape::Operator => single_style_span(self.theme.operator.normal(), shape.span),
-            FlatShape::DotDot => single_style_span(self.theme.dot_dot.bold(), shape.span),
-            FlatShape::Dot => single_style_span(Style::new().fg(self.theme.dot), shape.span),
+            FlatShape::Keyword => single_style_span(self.theme.keyword.to_style(), shape.span),
+            FlatShape::Variable => single_style_span(self.theme.variable.to_style(), shape.span),
+            FlatShape::Identifier => {
+                single_style_span(self.theme.identifier.to_style(), shape.span)
+            }
+            FlatShape::Type => single_style_span(self.theme.r#type.to_style(), shape.span),
+            FlatShape::Operator => single_style_span(self.theme.operator.to_style(), shape.span),
+            FlatShape::DotDot => single_style_span(self.theme.dot_dot.to_style(), shape.span),
+            FlatShape::Dot => single_style_span(self.theme.dot.to_style(), shape.span),
             FlatShape::InternalCommand => {
-                single_style_span(self.theme.internal_command.bold(), shape.span)
+                single_style_span(self.theme.internal_command.to_style(), shape.span)
             }
             FlatShape::ExternalCommand => {
-                single_style_span(self.theme.external_command.normal(), shape.span)
+                single_style_span(self.theme.external_command.to_style(), shape.span)
             }
             FlatShape::ExternalWord => {
-                single_style_span(self.theme.external_word.bold(), shape.span)
+                single_style_span(self.theme.external_word.to_style(), shape.span)
+            }
+            FlatShape::BareMember => {
+                single_style_span(self.theme.bare_member.to_style(), shape.span)
             }
-            FlatShape::BareMember => single_style_span(self.theme.bare_member.bold(), shape.span),
             FlatShape::StringMember => {
-                single_style_span(self.theme.string_member.bold(), shape.span)
+                single_style_span(self.theme.string_member.to_style(), shape.span)
             }
-            FlatShape::String => single_style_span(self.theme.string.normal(), shape.span),
-            FlatShape::Path => single_style_span(self.theme.path.normal(), shape.span),
-            FlatShape::GlobPattern => single_style_span(self.theme.glob_pattern.bold(), shape.span),
-            FlatShape::Word => single_style_span(self.theme.word.normal(), shape.span),
-            FlatShape::Pipe => single_style_span(self.theme.pipe.bold(), shape.span),
-            FlatShape::Flag => single_style_span(self.theme.flag.bold(), shape.span),
+            FlatShape::String => single_style_span(self.theme.string.to_style(), shape.span),
+            FlatShape::Path => single_style_span(self.theme.path.to_style(), shape.span),
+            FlatShape::GlobPattern => {
+                single_style_span(self.theme.glob_pattern.to_style(), shape.span)
+            }
+            FlatShape::Word => single_style_span(self.theme.word.to_style(), shape.span),
+            FlatShape::Pipe => single_style_span(self.theme.pipe.to_style(), shape.span),
+            FlatShape::Flag => single_style_span(self.theme.flag.to_style(), shape.span),
             FlatShape::ShorthandFlag => {
-                single_style_span(self.theme.shorthand_flag.bold(), shape.span)
+                single_style_span(self.theme.shorthand_flag.to_style(), shape.span)
+            }
+            FlatShape::Int => single_style_span(self.theme.int.to_style(), shape.span),
+            FlatShape::Decimal => single_style_span(self.theme.decimal.to_style(), shape.span),
+            FlatShape::Whitespace => {
+                single_style_span(self.theme.whitespace.to_style(), shape.span)
             }
-            FlatShape::Int => single_style_span(self.theme.int.bold(), shape.span),
-            FlatShape::Decimal => single_style_span(self.theme.decimal.bold(), shape.span),
-            FlatShape::Whitespace => single_style_span(self.theme.whitespace.normal(), shape.span),
-            FlatShape::Separator => single_style_span(self.theme.separator.normal(), shape.span),
-            FlatShape::Comment => single_style_span(self.theme.comment.bold(), shape.span),
-            FlatShape::Garbage => single_style_span(
-                Style::new().fg(self.theme.garbage).on(Color::Red),
-                shape.span,
-            ),
+            FlatShape::Separator => single_style_span(self.theme.separator.to_style(), shape.span),
+            FlatShape::Comment => single_style_span(self.theme.comment.to_style(), shape.span),
+            FlatShape::Garbage => single_style_span(self.theme.garbage.to_style(), shape.span),
             FlatShape::Size { number, unit } => vec![
                 Spanned::<Style> {
                     span: *number,
-                    item: self.theme.size_number.bold(),
+                    item: self.theme.size_number.to_style(),
                 },
                 Spanned::<Style> {
                     span: *unit,
-                    item: self.theme.size_unit.bold(),
+                    item: self.theme.size_unit.to_style(),
                 },
             ],
         }
@@ -158,99 +221,436 @@ impl From<serde_json::error::Error> for ThemeError {
     }
 }
 
+/// A style description for a single `FlatShape`: an optional foreground and
+/// background color plus a set of text attributes. Deserializes from either
+/// a bare color string (shorthand for "this fg, default attributes") or an
+/// object with `fg`/`bg`/`attributes` keys.
+#[derive(Debug, Clone, PartialEq)]
+struct StyleSpec {
+    fg: Option<Color>,
+    bg: Option<Color>,
+    attributes: Vec<Attribute>,
+}
+
+impl StyleSpec {
+    fn to_style(&self) -> Style {
+        let mut style = Style::new();
+        if let Some(fg) = self.fg {
+            style = style.fg(fg);
+        }
+        if let Some(bg) = self.bg {
+            style = style.on(bg);
+        }
+        for attribute in &self.attributes {
+            style = match attribute {
+                Attribute::Bold => style.bold(),
+                Attribute::Dimmed => style.dimmed(),
+                Attribute::Italic => style.italic(),
+                Attribute::Underline => style.underline(),
+                Attribute::Reverse => style.reverse(),
+            };
+        }
+        style
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Attribute {
+    Bold,
+    Dimmed,
+    Italic,
+    Underline,
+    Reverse,
+}
+
+impl<'de> Deserialize<'de> for StyleSpec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Shorthand(String),
+            Full {
+                #[serde(default)]
+                fg: Option<String>,
+                #[serde(default)]
+                bg: Option<String>,
+                #[serde(default)]
+                attributes: Vec<Attribute>,
+            },
+        }
+
+        match Repr::deserialize(deserializer)? {
+            Repr::Shorthand(s) => Ok(StyleSpec {
+                fg: Some(string_and_color::to_color::<D::Error>(&s)?),
+                bg: None,
+                attributes: Vec::new(),
+            }),
+            Repr::Full { fg, bg, attributes } => Ok(StyleSpec {
+                fg: fg
+                    .as_deref()
+                    .map(string_and_color::to_color::<D::Error>)
+                    .transpose()?,
+                bg: bg
+                    .as_deref()
+                    .map(string_and_color::to_color::<D::Error>)
+                    .transpose()?,
+                attributes,
+            }),
+        }
+    }
+}
+
+impl Serialize for StyleSpec {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if self.bg.is_none() && self.attributes.is_empty() {
+            if let Some(fg) = &self.fg {
+                return serializer
+                    .serialize_str(&string_and_color::color_to_string::<S::Error>(fg)?);
+            }
+        }
+
+        #[derive(Serialize)]
+        struct Full {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            fg: Option<String>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            bg: Option<String>,
+            #[serde(skip_serializing_if = "Vec::is_empty")]
+            attributes: Vec<Attribute>,
+        }
+
+        Full {
+            fg: self
+                .fg
+                .as_ref()
+                .map(string_and_color::color_to_string::<S::Error>)
+                .transpose()?,
+            bg: self
+                .bg
+                .as_ref()
+                .map(string_and_color::color_to_string::<S::Error>)
+                .transpose()?,
+            attributes: self.attributes.clone(),
+        }
+        .serialize(serializer)
+    }
+}
+
+/// A theme is an overlay on top of [`DefaultPalette`]'s colors: any
+/// `FlatShape` entry left out of the JSON falls back to its compiled-in
+/// default via `#[serde(default = ...)]`, so a theme file only needs to
+/// spell out the shapes it wants to change.
 #[derive(Serialize, Deserialize, Debug)]
 struct Theme {
-    #[serde(with = "string_and_color")]
-    open_delimiter: Color,
-    #[serde(with = "string_and_color")]
-    close_delimiter: Color,
-    #[serde(with = "string_and_color")]
-    r#type: Color,
-    #[serde(with = "string_and_color")]
-    identifier: Color,
-    #[serde(with = "string_and_color")]
-    it_variable: Color,
-    #[serde(with = "string_and_color")]
-    variable: Color,
-    #[serde(with = "string_and_color")]
-    operator: Color,
-    #[serde(with = "string_and_color")]
-    dot: Color,
-    #[serde(with = "string_and_color")]
-    dot_dot: Color,
-    #[serde(with = "string_and_color")]
-    internal_command: Color,
-    #[serde(with = "string_and_color")]
-    external_command: Color,
-    #[serde(with = "string_and_color")]
-    external_word: Color,
-    #[serde(with = "string_and_color")]
-    bare_member: Color,
-    #[serde(with = "string_and_color")]
-    string_member: Color,
-    #[serde(with = "string_and_color")]
-    string: Color,
-    #[serde(with = "string_and_color")]
-    path: Color,
-    #[serde(with = "string_and_color")]
-    word: Color,
-    #[serde(with = "string_and_color")]
-    keyword: Color,
-    #[serde(with = "string_and_color")]
-    pipe: Color,
-    #[serde(with = "string_and_color")]
-    glob_pattern: Color,
-    #[serde(with = "string_and_color")]
-    flag: Color,
-    #[serde(with = "string_and_color")]
-    shorthand_flag: Color,
-    #[serde(with = "string_and_color")]
-    int: Color,
-    #[serde(with = "string_and_color")]
-    decimal: Color,
-    #[serde(with = "string_and_color")]
-    garbage: Color,
-    #[serde(with = "string_and_color")]
-    whitespace: Color,
-    #[serde(with = "string_and_color")]
-    separator: Color,
-    #[serde(with = "string_and_color")]
-    comment: Color,
-    #[serde(with = "string_and_color")]
-    size_number: Color,
-    #[serde(with = "string_and_color")]
-    size_unit: Color,
+    /// Recognized for forward compatibility with additional built-in base
+    /// palettes; the only base today is the implicit default, so this is
+    /// currently a no-op other than documenting intent in theme files.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    extends: Option<String>,
+    #[serde(default = "default_open_delimiter")]
+    open_delimiter: StyleSpec,
+    #[serde(default = "default_close_delimiter")]
+    close_delimiter: StyleSpec,
+    #[serde(default = "default_type")]
+    r#type: StyleSpec,
+    #[serde(default = "default_identifier")]
+    identifier: StyleSpec,
+    #[serde(default = "default_it_variable")]
+    it_variable: StyleSpec,
+    #[serde(default = "default_variable")]
+    variable: StyleSpec,
+    #[serde(default = "default_operator")]
+    operator: StyleSpec,
+    #[serde(default = "default_dot")]
+    dot: StyleSpec,
+    #[serde(default = "default_dot_dot")]
+    dot_dot: StyleSpec,
+    #[serde(default = "default_internal_command")]
+    internal_command: StyleSpec,
+    #[serde(default = "default_external_command")]
+    external_command: StyleSpec,
+    #[serde(default = "default_external_word")]
+    external_word: StyleSpec,
+    #[serde(default = "default_bare_member")]
+    bare_member: StyleSpec,
+    #[serde(default = "default_string_member")]
+    string_member: StyleSpec,
+    #[serde(default = "default_string")]
+    string: StyleSpec,
+    #[serde(default = "default_path")]
+    path: StyleSpec,
+    #[serde(default = "default_word")]
+    word: StyleSpec,
+    #[serde(default = "default_keyword")]
+    keyword: StyleSpec,
+    #[serde(default = "default_pipe")]
+    pipe: StyleSpec,
+    #[serde(default = "default_glob_pattern")]
+    glob_pattern: StyleSpec,
+    #[serde(default = "default_flag")]
+    flag: StyleSpec,
+    #[serde(default = "default_shorthand_flag")]
+    shorthand_flag: StyleSpec,
+    #[serde(default = "default_int")]
+    int: StyleSpec,
+    #[serde(default = "default_decimal")]
+    decimal: StyleSpec,
+    #[serde(default = "default_garbage")]
+    garbage: StyleSpec,
+    #[serde(default = "default_whitespace")]
+    whitespace: StyleSpec,
+    #[serde(default = "default_separator")]
+    separator: StyleSpec,
+    #[serde(default = "default_comment")]
+    comment: StyleSpec,
+    #[serde(default = "default_size_number")]
+    size_number: StyleSpec,
+    #[serde(default = "default_size_unit")]
+    size_unit: StyleSpec,
+}
+
+impl Default for Theme {
+    fn default() -> Theme {
+        Theme {
+            extends: None,
+            open_delimiter: default_open_delimiter(),
+            close_delimiter: default_close_delimiter(),
+            r#type: default_type(),
+            identifier: default_identifier(),
+            it_variable: default_it_variable(),
+            variable: default_variable(),
+            operator: default_operator(),
+            dot: default_dot(),
+            dot_dot: default_dot_dot(),
+            internal_command: default_internal_command(),
+            external_command: default_external_command(),
+            external_word: default_external_word(),
+            bare_member: default_bare_member(),
+            string_member: default_string_member(),
+            string: default_string(),
+            path: default_path(),
+            word: default_word(),
+            keyword: default_keyword(),
+            pipe: default_pipe(),
+            glob_pattern: default_glob_pattern(),
+            flag: default_flag(),
+            shorthand_flag: default_shorthand_flag(),
+            int: default_int(),
+            decimal: default_decimal(),
+            garbage: default_garbage(),
+            whitespace: default_whitespace(),
+            separator: default_separator(),
+            comment: default_comment(),
+            size_number: default_size_number(),
+            size_unit: default_size_unit(),
+        }
+    }
+}
+
+// These mirror DefaultPalette::styles_for_shape's hard-coded colors, so a
+// theme file that specifies only a few shapes still renders the rest
+// exactly like the compiled-in default.
+fn plain(fg: Color) -> StyleSpec {
+    StyleSpec {
+        fg: Some(fg),
+        bg: None,
+        attributes: Vec::new(),
+    }
+}
+
+fn bold(fg: Color) -> StyleSpec {
+    StyleSpec {
+        fg: Some(fg),
+        bg: None,
+        attributes: vec![Attribute::Bold],
+    }
+}
+
+fn default_open_delimiter() -> StyleSpec {
+    plain(Color::White)
+}
+fn default_close_delimiter() -> StyleSpec {
+    plain(Color::White)
+}
+fn default_type() -> StyleSpec {
+    bold(Color::Blue)
+}
+fn default_identifier() -> StyleSpec {
+    plain(Color::Purple)
+}
+fn default_it_variable() -> StyleSpec {
+    bold(Color::Purple)
+}
+fn default_variable() -> StyleSpec {
+    plain(Color::Purple)
+}
+fn default_operator() -> StyleSpec {
+    plain(Color::Yellow)
+}
+fn default_dot() -> StyleSpec {
+    plain(Color::White)
+}
+fn default_dot_dot() -> StyleSpec {
+    bold(Color::Yellow)
+}
+fn default_internal_command() -> StyleSpec {
+    bold(Color::Cyan)
+}
+fn default_external_command() -> StyleSpec {
+    plain(Color::Cyan)
+}
+fn default_external_word() -> StyleSpec {
+    bold(Color::Green)
+}
+fn default_bare_member() -> StyleSpec {
+    bold(Color::Yellow)
+}
+fn default_string_member() -> StyleSpec {
+    bold(Color::Yellow)
+}
+fn default_string() -> StyleSpec {
+    plain(Color::Green)
+}
+fn default_path() -> StyleSpec {
+    plain(Color::Cyan)
+}
+fn default_word() -> StyleSpec {
+    plain(Color::Green)
+}
+fn default_keyword() -> StyleSpec {
+    bold(Color::Purple)
+}
+fn default_pipe() -> StyleSpec {
+    bold(Color::Purple)
+}
+fn default_glob_pattern() -> StyleSpec {
+    bold(Color::Cyan)
+}
+fn default_flag() -> StyleSpec {
+    bold(Color::Blue)
+}
+fn default_shorthand_flag() -> StyleSpec {
+    bold(Color::Blue)
+}
+fn default_int() -> StyleSpec {
+    bold(Color::Purple)
+}
+fn default_decimal() -> StyleSpec {
+    bold(Color::Purple)
+}
+fn default_garbage() -> StyleSpec {
+    StyleSpec {
+        fg: Some(Color::White),
+        bg: Some(Color::Red),
+        attributes: Vec::new(),
+    }
+}
+fn default_whitespace() -> StyleSpec {
+    plain(Color::White)
+}
+fn default_separator() -> StyleSpec {
+    plain(Color::White)
+}
+fn default_comment() -> StyleSpec {
+    bold(Color::Green)
+}
+fn default_size_number() -> StyleSpec {
+    bold(Color::Purple)
+}
+fn default_size_unit() -> StyleSpec {
+    bold(Color::Cyan)
 }
 
 mod string_and_color {
     use ansi_term::Color;
-    use serde::{self, Deserialize, Deserializer, Serializer};
     use std::str::Bytes;
 
-    pub fn serialize<S>(color: &Color, serializer: S) -> Result<S::Ok, S::Error>
+    pub(super) fn color_to_string<E>(color: &Color) -> Result<String, E>
     where
-        S: Serializer,
+        E: serde::ser::Error,
     {
-        serializer.serialize_str("TODO: IMPLEMENT SERIALIZATION")
+        Ok(match color {
+            Color::Black => "black".to_string(),
+            Color::Red => "red".to_string(),
+            Color::Green => "green".to_string(),
+            Color::Yellow => "yellow".to_string(),
+            Color::Blue => "blue".to_string(),
+            Color::Purple => "purple".to_string(),
+            Color::Cyan => "cyan".to_string(),
+            Color::White => "white".to_string(),
+            Color::Fixed(n) => n.to_string(),
+            Color::RGB(r, g, b) => format!("{:02x}{:02x}{:02x}", r, g, b),
+        })
     }
 
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<Color, D::Error>
+    fn named_color(name: &str) -> Option<Color> {
+        Some(match name {
+            "black" => Color::Black,
+            "dark_gray" | "dark_grey" => Color::Fixed(8),
+            "red" => Color::Red,
+            "dark_red" => Color::Fixed(1),
+            "green" => Color::Green,
+            "dark_green" => Color::Fixed(2),
+            "yellow" => Color::Yellow,
+            "dark_yellow" => Color::Fixed(3),
+            "blue" => Color::Blue,
+            "dark_blue" => Color::Fixed(4),
+            "purple" | "magenta" => Color::Purple,
+            "dark_purple" | "dark_magenta" => Color::Fixed(5),
+            "cyan" => Color::Cyan,
+            "dark_cyan" => Color::Fixed(6),
+            "white" => Color::White,
+            "dark_white" => Color::Fixed(7),
+            _ => return None,
+        })
+    }
+
+    pub(super) fn to_color<E>(s: &str) -> Result<Color, E>
     where
-        D: Deserializer<'de>,
+        E: serde::de::Error,
     {
-        let s = String::deserialize(deserializer)?;
-        to_color(&s)
+        if let Some(color) = named_color(&s.to_ascii_lowercase()) {
+            return Ok(color);
+        }
+
+        if let Ok(index) = s.parse::<u16>() {
+            if index <= 255 {
+                return Ok(Color::Fixed(index as u8));
+            }
+        }
+
+        let hex = s.strip_prefix('#').unwrap_or(s);
+        let hex = match hex.len() {
+            3 => hex
+                .chars()
+                .flat_map(|c| std::iter::repeat(c).take(2))
+                .collect(),
+            _ => hex.to_string(),
+        };
+
+        let mut bytes = hex.bytes();
+        let r = xtoi(&mut bytes).map_err(|_| invalid_color::<E>(s))?;
+        let g = xtoi(&mut bytes).map_err(|_| invalid_color::<E>(s))?;
+        let b = xtoi(&mut bytes).map_err(|_| invalid_color::<E>(s))?;
+        Ok(Color::RGB(r, g, b))
     }
 
-    fn to_color<E>(s: &str) -> Result<Color, E>
+    fn invalid_color<E>(s: &str) -> E
     where
         E: serde::de::Error,
     {
-        let mut bytes = s.bytes();
-        let r = xtoi(&mut bytes)?;
-        let g = xtoi(&mut bytes)?;
-        let b = xtoi(&mut bytes)?;
-        Ok(Color::RGB(r, g, b))
+        E::custom(format!(
+            "invalid color `{}`: expected a color name (e.g. \"red\", \"dark_blue\"), \
+             a 0-255 ANSI palette index, or a hex string like \"#a1b2c3\" or \"#abc\"",
+            s
+        ))
     }
 
     fn xtoi<E>(b: &mut Bytes) -> Result<u8, E>
@@ -270,8 +670,9 @@ mod string_and_color {
     {
         match character {
             b'0'..=b'9' => Ok(character - b'0'),
-            b'a'..=b'z' => Ok(character - (b'a' - 10)),
-            _ => return Err(E::custom(format!("invalid charater {}", character))),
+            b'a'..=b'f' => Ok(character - (b'a' - 10)),
+            b'A'..=b'F' => Ok(character - (b'A' - 10)),
+            _ => Err(E::custom(format!("invalid character {}", character))),
         }
     }
 }
@@ -282,10 +683,11 @@ fn single_style_span(style: Style, span: Span) -> Vec<Spanned<Style>> {
 
 #[cfg(test)]
 mod tests {
-    use super::{Palette, ThemedPallet};
-    use ansi_term::Color;
+    use super::{ColorGated, DefaultPalette, Palette, ThemedPallet};
+    use ansi_term::{Color, Style};
     use nu_protocol::hir::FlatShape;
     use nu_source::{Span, Spanned};
+    use std::env;
     use std::io::Cursor;
 
     #[test]
@@ -334,9 +736,203 @@ mod tests {
         assert_eq!(
             styled[0],
             Spanned {
-                item: Color::RGB(163, 89, 204).bold(),
+                item: Color::RGB(163, 89, 204).normal(),
                 span: Span::new(4, 9),
             },
         );
+
+        let mut dumped = Vec::new();
+        themed_palette.to_writer(&mut dumped).unwrap();
+        let reloaded = ThemedPallet::new(&mut Cursor::new(dumped)).unwrap();
+        assert_eq!(
+            reloaded.styles_for_shape(&test_shape),
+            themed_palette.styles_for_shape(&test_shape)
+        );
+    }
+
+    #[test]
+    fn parses_named_indexed_and_short_hex_colors() {
+        let json = r##"
+{
+    "open_delimiter": "a359cc",
+    "close_delimiter": "a359cc",
+    "type": "200",
+    "identifier": "#abc",
+    "it_variable": "ABC123",
+    "variable": "a359cc",
+    "operator": "a359cc",
+    "dot": "a359cc",
+    "dot_dot": "a359cc",
+    "internal_command": "a359cc",
+    "external_command": "a359cc",
+    "external_word": "a359cc",
+    "bare_member": "a359cc",
+    "string_member": "a359cc",
+    "string": "red",
+    "path": "a359cc",
+    "word": "a359cc",
+    "keyword": "a359cc",
+    "pipe": "a359cc",
+    "glob_pattern": "a359cc",
+    "flag": "a359cc",
+    "shorthand_flag": "a359cc",
+    "int": "a359cc",
+    "decimal": "a359cc",
+    "garbage": "a359cc",
+    "whitespace": "a359cc",
+    "separator": "a359cc",
+    "comment": "a359cc",
+    "size_number": "a359cc",
+    "size_unit": "a359cc"
+}"##;
+        let themed_palette = ThemedPallet::new(&mut Cursor::new(json)).unwrap();
+
+        let shape = |item| Spanned {
+            item,
+            span: Span::new(0, 1),
+        };
+
+        assert_eq!(
+            themed_palette.styles_for_shape(&shape(FlatShape::String))[0].item,
+            Color::Red.normal()
+        );
+        assert_eq!(
+            themed_palette.styles_for_shape(&shape(FlatShape::Type))[0].item,
+            Color::Fixed(200).normal()
+        );
+        assert_eq!(
+            themed_palette.styles_for_shape(&shape(FlatShape::Identifier))[0].item,
+            Color::RGB(170, 187, 204).normal()
+        );
+        assert_eq!(
+            themed_palette.styles_for_shape(&shape(FlatShape::ItVariable))[0].item,
+            Color::RGB(171, 193, 35).normal()
+        );
+    }
+
+    #[test]
+    fn style_spec_objects_carry_bg_and_attributes() {
+        let json = r#"
+{
+    "open_delimiter": "a359cc",
+    "close_delimiter": "a359cc",
+    "type": "a359cc",
+    "identifier": "a359cc",
+    "it_variable": "a359cc",
+    "variable": "a359cc",
+    "operator": "a359cc",
+    "dot": "a359cc",
+    "dot_dot": "a359cc",
+    "internal_command": "a359cc",
+    "external_command": "a359cc",
+    "external_word": "a359cc",
+    "bare_member": "a359cc",
+    "string_member": "a359cc",
+    "string": "a359cc",
+    "path": "a359cc",
+    "word": "a359cc",
+    "keyword": "a359cc",
+    "pipe": "a359cc",
+    "glob_pattern": "a359cc",
+    "flag": "a359cc",
+    "shorthand_flag": "a359cc",
+    "int": "a359cc",
+    "decimal": "a359cc",
+    "garbage": { "fg": "white", "bg": "red" },
+    "whitespace": "a359cc",
+    "separator": "a359cc",
+    "comment": { "fg": "green", "attributes": ["dimmed", "italic"] },
+    "size_number": "a359cc",
+    "size_unit": "a359cc"
+}"#;
+        let themed_palette = ThemedPallet::new(&mut Cursor::new(json)).unwrap();
+
+        let shape = |item| Spanned {
+            item,
+            span: Span::new(0, 1),
+        };
+
+        assert_eq!(
+            themed_palette.styles_for_shape(&shape(FlatShape::Garbage))[0].item,
+            Style::new().fg(Color::White).on(Color::Red)
+        );
+        assert_eq!(
+            themed_palette.styles_for_shape(&shape(FlatShape::Comment))[0].item,
+            Color::Green.dimmed().italic()
+        );
+
+        let mut dumped = Vec::new();
+        themed_palette.to_writer(&mut dumped).unwrap();
+        let reloaded = ThemedPallet::new(&mut Cursor::new(dumped)).unwrap();
+        assert_eq!(
+            reloaded.styles_for_shape(&shape(FlatShape::Comment)),
+            themed_palette.styles_for_shape(&shape(FlatShape::Comment))
+        );
+    }
+
+    #[test]
+    fn color_gated_respects_no_color_and_clicolor_force() {
+        env::remove_var("CLICOLOR_FORCE");
+        env::remove_var("NO_COLOR");
+        env::remove_var("CLICOLOR");
+
+        env::set_var("NO_COLOR", "1");
+        assert!(!ColorGated::<DefaultPalette>::should_style());
+        env::remove_var("NO_COLOR");
+
+        env::set_var("CLICOLOR", "0");
+        assert!(!ColorGated::<DefaultPalette>::should_style());
+        env::remove_var("CLICOLOR");
+
+        env::set_var("NO_COLOR", "1");
+        env::set_var("CLICOLOR_FORCE", "1");
+        assert!(ColorGated::<DefaultPalette>::should_style());
+        env::remove_var("NO_COLOR");
+        env::remove_var("CLICOLOR_FORCE");
+    }
+
+    #[test]
+    fn color_gated_emits_default_style_when_disabled() {
+        let gated = ColorGated {
+            inner: DefaultPalette {},
+            enabled: false,
+        };
+        let shape = Spanned {
+            item: FlatShape::Type,
+            span: Span::new(0, 4),
+        };
+        let styled = gated.styles_for_shape(&shape);
+        assert_eq!(
+            styled,
+            vec![Spanned {
+                item: Style::default(),
+                span: Span::new(0, 4),
+            }]
+        );
+    }
+
+    #[test]
+    fn partial_theme_inherits_unspecified_shapes_from_the_default_palette() {
+        let json = r#"{ "extends": "default", "string": "red", "comment": "200" }"#;
+        let themed_palette = ThemedPallet::new(&mut Cursor::new(json)).unwrap();
+        let default_palette = DefaultPalette {};
+
+        let shape = |item| Spanned {
+            item,
+            span: Span::new(0, 1),
+        };
+
+        assert_eq!(
+            themed_palette.styles_for_shape(&shape(FlatShape::String))[0].item,
+            Color::Red.normal()
+        );
+        assert_eq!(
+            themed_palette.styles_for_shape(&shape(FlatShape::Comment))[0].item,
+            Color::Fixed(200).normal()
+        );
+        assert_eq!(
+            themed_palette.styles_for_shape(&shape(FlatShape::Type)),
+            default_palette.styles_for_shape(&shape(FlatShape::Type))
+        );
     }
 }
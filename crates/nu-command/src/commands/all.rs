@@ -3,9 +3,11 @@ use nu_engine::evaluate_baseline_expr;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
 use nu_protocol::{
-    hir::CapturedBlock, hir::ClassifiedCommand, Signature, SyntaxShape, UntaggedValue,
+    hir::CapturedBlock, hir::ClassifiedCommand, hir::SpannedExpression, Signature, SyntaxShape,
+    UntaggedValue, Value,
 };
 use nu_stream::ToActionStream;
+use parking_lot::Mutex;
 
 pub struct Command;
 
@@ -32,12 +34,10 @@ impl WholeStreamCommand for Command {
     }
 
     fn run_with_actions(&self, args: CommandArgs) -> Result<ActionStream, ShellError> {
-        all(args)
+        quantify(args, true, |acc, cond| acc && cond, |acc| !acc, |acc| acc)
     }
 
     fn examples(&self) -> Vec<Example> {
-        use nu_protocol::Value;
-
         vec![
             Example {
                 description: "Find if services are running",
@@ -53,71 +53,100 @@ impl WholeStreamCommand for Command {
     }
 }
 
-fn all(args: CommandArgs) -> Result<ActionStream, ShellError> {
-    let ctx = Arc::new(EvaluationContext::from_args(&args));
-    let tag = args.call_info.name_tag.clone();
-    let (Arguments { block }, input) = args.process()?;
-
-    let condition = {
-        if block.block.block.len() != 1 {
-            return Err(ShellError::labeled_error(
+///Extracts the single condition expression out of a `RowCondition` block,
+///the same shape `all?`/`any?`/`none?` all parse their argument into.
+pub(crate) fn extract_condition(
+    block: &CapturedBlock,
+    tag: &Tag,
+) -> Result<SpannedExpression, ShellError> {
+    if block.block.block.len() != 1 {
+        return Err(ShellError::labeled_error(
+            "Expected a condition",
+            "expected a condition",
+            tag,
+        ));
+    }
+    match block.block.block[0].pipelines.get(0) {
+        Some(item) => match item.list.get(0) {
+            Some(ClassifiedCommand::Expr(expr)) => Ok(expr.clone()),
+            _ => Err(ShellError::labeled_error(
                 "Expected a condition",
                 "expected a condition",
                 tag,
-            ));
-        }
-        match block.block.block[0].pipelines.get(0) {
-            Some(item) => match item.list.get(0) {
-                Some(ClassifiedCommand::Expr(expr)) => expr.clone(),
-                _ => {
-                    return Err(ShellError::labeled_error(
-                        "Expected a condition",
-                        "expected a condition",
-                        tag,
-                    ));
+            )),
+        },
+        None => Err(ShellError::labeled_error(
+            "Expected a condition",
+            "expected a condition",
+            tag,
+        )),
+    }
+}
+
+///Binds `$it` (and the block's captured vars) to `row` for the duration of
+///evaluating `condition` against it, returning the resulting boolean.
+fn eval_condition(
+    condition: &SpannedExpression,
+    block: &CapturedBlock,
+    ctx: &EvaluationContext,
+    row: Value,
+) -> Result<bool, ShellError> {
+    ctx.scope.enter_scope();
+    ctx.scope.add_vars(&block.captured.entries);
+    ctx.scope.add_var("$it", row);
+    let result = evaluate_baseline_expr(condition, ctx);
+    ctx.scope.exit_scope();
+
+    result?.as_bool()
+}
+
+///Folds `input` into a single boolean quantifier result, short-circuiting
+///as soon as `is_decided` recognizes the running accumulator (after
+///`combine`s in the current row's condition) as final, instead of
+///evaluating the condition block against every remaining row. Shared by
+///`all?` (stops at the first `false`), `any?` (stops at the first `true`)
+///and `none?` (same as `any?`, with `finalize` negating the answer).
+pub(crate) fn quantify(
+    args: CommandArgs,
+    init: bool,
+    combine: fn(bool, bool) -> bool,
+    is_decided: fn(bool) -> bool,
+    finalize: fn(bool) -> bool,
+) -> Result<ActionStream, ShellError> {
+    let ctx = Arc::new(EvaluationContext::from_args(&args));
+    let tag = args.call_info.name_tag.clone();
+    let (Arguments { block }, input) = args.process()?;
+    let condition = extract_condition(&block, &tag)?;
+
+    let acc: Arc<Mutex<Result<bool, ShellError>>> = Arc::new(Mutex::new(Ok(init)));
+    let acc_loop = acc.clone();
+
+    input
+        .take_while(move |row| {
+            let mut acc = acc_loop.lock();
+            let current = match &*acc {
+                Ok(b) => *b,
+                Err(_) => return false,
+            };
+
+            match eval_condition(&condition, &block, &ctx, row.clone()) {
+                Ok(cond) => {
+                    let next = combine(current, cond);
+                    let keep_going = !is_decided(next);
+                    *acc = Ok(next);
+                    keep_going
+                }
+                Err(e) => {
+                    *acc = Err(e);
+                    false
                 }
-            },
-            None => {
-                return Err(ShellError::labeled_error(
-                    "Expected a condition",
-                    "expected a condition",
-                    tag,
-                ));
-            }
-        }
-    };
-
-    let init = Ok(InputStream::one(
-        UntaggedValue::boolean(true).into_value(&tag),
-    ));
-
-    Ok(input
-        .fold(init, move |acc, row| {
-            let condition = condition.clone();
-            let ctx = ctx.clone();
-            ctx.scope.enter_scope();
-            ctx.scope.add_vars(&block.captured.entries);
-            ctx.scope.add_var("$it", row);
-
-            let condition = evaluate_baseline_expr(&condition, &*ctx);
-            ctx.scope.exit_scope();
-
-            let curr = acc?.drain_vec();
-            let curr = curr
-                .get(0)
-                .ok_or_else(|| ShellError::unexpected("No value to check with"))?;
-            let cond = curr.as_bool()?;
-
-            match condition {
-                Ok(condition) => match condition.as_bool() {
-                    Ok(b) => Ok(InputStream::one(
-                        UntaggedValue::boolean(cond && b).into_value(&curr.tag),
-                    )),
-                    Err(e) => Err(e),
-                },
-                Err(e) => Err(e),
             }
-        })?
+        })
+        .for_each(drop);
+
+    let result = acc.lock().clone()?;
+
+    Ok(InputStream::one(UntaggedValue::boolean(finalize(result)).into_value(&tag))
         .to_action_stream())
 }
 
@@ -0,0 +1,57 @@
+use super::all::quantify;
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape, Value};
+
+pub struct Command;
+
+impl WholeStreamCommand for Command {
+    fn name(&self) -> &str {
+        "any?"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("any?").required(
+            "condition",
+            SyntaxShape::RowCondition,
+            "the condition that must match",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Find if the table rows matches the condition."
+    }
+
+    fn run_with_actions(&self, args: CommandArgs) -> Result<ActionStream, ShellError> {
+        quantify(args, false, |acc, cond| acc || cond, |acc| acc, |acc| acc)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Find if any services are not running",
+                example: "echo [[status]; [UP] [DOWN]] | any? status == DOWN",
+                result: Some(vec![Value::from(true)]),
+            },
+            Example {
+                description: "Check if any of the values is odd",
+                example: "echo [2 4 6 8] | any? $(= $it mod 2) == 1",
+                result: Some(vec![Value::from(false)]),
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Command;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(Command {})
+    }
+}
@@ -30,26 +30,37 @@ impl WholeStreamCommand for Command {
     fn run_with_actions(&self, args: CommandArgs) -> Result<ActionStream, ShellError> {
         let (Arguments { mut value }, input) = args.process()?;
 
-        let input: Vec<Value> = input.collect();
+        let mut input = input.peekable();
 
-        if let Some(first) = input.get(0) {
+        if let Some(first) = input.peek() {
             value.tag = first.tag();
         }
 
-        // Checks if we are trying to append a row literal
-        if let Value {
+        // Checks if we are trying to append a row literal, unwrapping a
+        // single row into just that row, and a multi-row table into each
+        // of its rows so they are appended individually.
+        let appended: Vec<Value> = if let Value {
             value: UntaggedValue::Table(values),
             tag,
         } = &value
         {
-            if values.len() == 1 && values[0].is_row() {
-                value = values[0].value.clone().into_value(tag);
+            if !values.is_empty() && values.iter().all(|row| row.is_row()) {
+                values
+                    .iter()
+                    .map(|row| row.value.clone().into_value(tag))
+                    .collect()
+            } else {
+                vec![value.clone()]
             }
-        }
+        } else {
+            vec![value]
+        };
 
+        // `input` is only ever peeked above, so the upstream table is
+        // forwarded lazily and the appended row(s) are chained on once it
+        // is exhausted, rather than collecting it into memory up front.
         Ok(input
-            .into_iter()
-            .chain(vec![value])
+            .chain(appended)
             .map(ReturnSuccess::value)
             .to_output_stream_with_actions())
     }
@@ -0,0 +1,77 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use std::sync::Arc;
+
+pub struct SubCommand;
+
+#[derive(Deserialize)]
+struct Arguments {
+    rest: Vec<ColumnPath>,
+}
+
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "into binary"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("into binary").rest(
+            SyntaxShape::ColumnPath,
+            "convert data at the given cell paths, leaving the rest of the row untouched",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Convert value to binary"
+    }
+
+    fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        let (Arguments { rest }, input) = args.process()?;
+        let paths = Arc::new(rest);
+
+        Ok(input
+            .map(move |v| ReturnSuccess::value(convert(v, &paths)?))
+            .to_output_stream())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Convert string to binary",
+            example: "echo 'hello' | into binary",
+            result: None,
+        }]
+    }
+}
+
+fn convert(value: Value, paths: &[ColumnPath]) -> Result<Value, ShellError> {
+    if paths.is_empty() {
+        return action(&value);
+    }
+
+    let mut value = value;
+    for path in paths {
+        value = value.swap_data_by_column_path(path, Box::new(|old| action(old)))?;
+    }
+    Ok(value)
+}
+
+fn action(value: &Value) -> Result<Value, ShellError> {
+    let tag = value.tag.clone();
+
+    let bytes = match &value.value {
+        UntaggedValue::Primitive(Primitive::Binary(b)) => b.clone(),
+        UntaggedValue::Primitive(Primitive::String(s)) => s.as_bytes().to_vec(),
+        UntaggedValue::Primitive(Primitive::Int(i)) => i.to_le_bytes().to_vec(),
+        _ => {
+            return Err(ShellError::labeled_error(
+                format!("Could not convert {} to binary", value.type_name()),
+                "cannot convert to binary",
+                &tag,
+            ))
+        }
+    };
+
+    Ok(UntaggedValue::binary(bytes).into_value(tag))
+}
@@ -0,0 +1,28 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, UntaggedValue};
+
+pub struct Command;
+
+impl WholeStreamCommand for Command {
+    fn name(&self) -> &str {
+        "into"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("into")
+    }
+
+    fn usage(&self) -> &str {
+        "Convert values to a different type, in place or one cell path at a time"
+    }
+
+    fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        let name_tag = args.call_info.name_tag.clone();
+
+        Ok(OutputStream::one(Ok(ReturnSuccess::Value(
+            UntaggedValue::string(self.usage()).into_value(name_tag),
+        ))))
+    }
+}
@@ -0,0 +1,77 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+pub struct SubCommand;
+
+#[derive(Deserialize)]
+struct Arguments {
+    rest: Vec<ColumnPath>,
+}
+
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "into filepath"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("into filepath").rest(
+            SyntaxShape::ColumnPath,
+            "convert data at the given cell paths, leaving the rest of the row untouched",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Convert value to filepath"
+    }
+
+    fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        let (Arguments { rest }, input) = args.process()?;
+        let paths = Arc::new(rest);
+
+        Ok(input
+            .map(move |v| ReturnSuccess::value(convert(v, &paths)?))
+            .to_output_stream())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Convert string to filepath",
+            example: "echo 'Cargo.toml' | into filepath",
+            result: None,
+        }]
+    }
+}
+
+fn convert(value: Value, paths: &[ColumnPath]) -> Result<Value, ShellError> {
+    if paths.is_empty() {
+        return action(&value);
+    }
+
+    let mut value = value;
+    for path in paths {
+        value = value.swap_data_by_column_path(path, Box::new(|old| action(old)))?;
+    }
+    Ok(value)
+}
+
+fn action(value: &Value) -> Result<Value, ShellError> {
+    let tag = value.tag.clone();
+
+    let path = match &value.value {
+        UntaggedValue::Primitive(Primitive::FilePath(p)) => p.clone(),
+        UntaggedValue::Primitive(Primitive::String(s)) => PathBuf::from(s),
+        _ => {
+            return Err(ShellError::labeled_error(
+                format!("Could not convert {} to filepath", value.type_name()),
+                "cannot convert to filepath",
+                &tag,
+            ))
+        }
+    };
+
+    Ok(UntaggedValue::filepath(path).into_value(tag))
+}
@@ -0,0 +1,95 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use num_traits::cast::ToPrimitive;
+use std::sync::Arc;
+
+pub struct SubCommand;
+
+#[derive(Deserialize)]
+struct Arguments {
+    rest: Vec<ColumnPath>,
+}
+
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "into int"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("into int").rest(
+            SyntaxShape::ColumnPath,
+            "convert data at the given cell paths, leaving the rest of the row untouched",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Convert value to integer"
+    }
+
+    fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        let (Arguments { rest }, input) = args.process()?;
+        let paths = Arc::new(rest);
+
+        Ok(input
+            .map(move |v| ReturnSuccess::value(convert(v, &paths)?))
+            .to_output_stream())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Convert string to integer",
+                example: "echo '255' | into int",
+                result: Some(vec![Value::from(UntaggedValue::int(255))]),
+            },
+            Example {
+                description: "Convert only the `count` column of a table to integer",
+                example: "open data.csv | into int count",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn convert(value: Value, paths: &[ColumnPath]) -> Result<Value, ShellError> {
+    if paths.is_empty() {
+        return action(&value);
+    }
+
+    let mut value = value;
+    for path in paths {
+        value = value.swap_data_by_column_path(path, Box::new(|old| action(old)))?;
+    }
+    Ok(value)
+}
+
+fn action(value: &Value) -> Result<Value, ShellError> {
+    let tag = value.tag.clone();
+
+    let out = match &value.value {
+        UntaggedValue::Primitive(Primitive::Int(i)) => UntaggedValue::int(*i),
+        UntaggedValue::Primitive(Primitive::Boolean(b)) => UntaggedValue::int(if *b { 1 } else { 0 }),
+        UntaggedValue::Primitive(Primitive::Decimal(d)) => UntaggedValue::int(
+            d.to_i64()
+                .ok_or_else(|| convert_error("decimal", &tag))?,
+        ),
+        UntaggedValue::Primitive(Primitive::String(s)) => UntaggedValue::int(
+            s.trim()
+                .parse::<i64>()
+                .map_err(|_| convert_error("string", &tag))?,
+        ),
+        _ => return Err(convert_error(value.type_name().as_str(), &tag)),
+    };
+
+    Ok(out.into_value(tag))
+}
+
+fn convert_error(from: &str, tag: &Tag) -> ShellError {
+    ShellError::labeled_error(
+        format!("Could not convert {} to integer", from),
+        "cannot convert to integer",
+        tag,
+    )
+}
@@ -0,0 +1,106 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use num_traits::cast::ToPrimitive;
+use nu_source::Tagged;
+use std::sync::Arc;
+
+pub struct SubCommand;
+
+#[derive(Deserialize)]
+struct Arguments {
+    rest: Vec<ColumnPath>,
+    decimals: Option<Tagged<usize>>,
+}
+
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "into string"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("into string")
+            .rest(
+                SyntaxShape::ColumnPath,
+                "convert data at the given cell paths, leaving the rest of the row untouched",
+            )
+            .named(
+                "decimals",
+                SyntaxShape::Int,
+                "round decimal values to this many fractional digits",
+                None,
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Convert value to string"
+    }
+
+    fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        let (Arguments { rest, decimals }, input) = args.process()?;
+        let paths = Arc::new(rest);
+        let decimals = decimals.map(|d| d.item);
+
+        Ok(input
+            .map(move |v| ReturnSuccess::value(convert(v, &paths, decimals)?))
+            .to_output_stream())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Convert decimal to string",
+                example: "echo 3.1415 | into string",
+                result: Some(vec![Value::from(UntaggedValue::string("3.1415"))]),
+            },
+            Example {
+                description: "Convert decimal to string, rounded to 2 decimal places",
+                example: "echo 3.1415 | into string --decimals 2",
+                result: Some(vec![Value::from(UntaggedValue::string("3.14"))]),
+            },
+        ]
+    }
+}
+
+fn convert(value: Value, paths: &[ColumnPath], decimals: Option<usize>) -> Result<Value, ShellError> {
+    if paths.is_empty() {
+        return action(&value, decimals);
+    }
+
+    let mut value = value;
+    for path in paths {
+        value = value.swap_data_by_column_path(path, Box::new(move |old| action(old, decimals)))?;
+    }
+    Ok(value)
+}
+
+fn action(value: &Value, decimals: Option<usize>) -> Result<Value, ShellError> {
+    let tag = value.tag.clone();
+
+    let string = match &value.value {
+        UntaggedValue::Primitive(Primitive::String(s)) => s.clone(),
+        UntaggedValue::Primitive(Primitive::Int(i)) => i.to_string(),
+        UntaggedValue::Primitive(Primitive::Boolean(b)) => b.to_string(),
+        UntaggedValue::Primitive(Primitive::Decimal(d)) => match decimals {
+            Some(decimals) => format!("{:.*}", decimals, d.to_f64().ok_or_else(|| {
+                ShellError::labeled_error(
+                    "Could not convert decimal to string",
+                    "cannot convert to string",
+                    &tag,
+                )
+            })?),
+            None => d.to_string(),
+        },
+        UntaggedValue::Primitive(Primitive::FilePath(p)) => p.to_string_lossy().to_string(),
+        _ => {
+            return Err(ShellError::labeled_error(
+                format!("Could not convert {} to string", value.type_name()),
+                "cannot convert to string",
+                &tag,
+            ))
+        }
+    };
+
+    Ok(UntaggedValue::string(string).into_value(tag))
+}
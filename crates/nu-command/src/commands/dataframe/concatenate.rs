@@ -0,0 +1,67 @@
+use crate::{commands::dataframe::utils::parse_polars_error, prelude::*};
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{
+    dataframe::{NuDataFrame, PolarsData},
+    Signature, SyntaxShape, UntaggedValue, Value,
+};
+
+pub struct DataFrame;
+
+impl WholeStreamCommand for DataFrame {
+    fn name(&self) -> &str {
+        "dataframe concatenate"
+    }
+
+    fn usage(&self) -> &str {
+        "[DataFrame] Concatenates the rows of two dataframes with matching schemas"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("dataframe concatenate").required(
+            "other",
+            SyntaxShape::Any,
+            "the dataframe to append",
+        )
+    }
+
+    fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        command(args)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Concatenates the rows of two dataframes",
+            example: "let a = ([[a]; [1]] | dataframe to-df); $a | dataframe concatenate $a",
+            result: None,
+        }]
+    }
+}
+
+fn command(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let tag = args.call_info.name_tag.clone();
+    let mut args = args.evaluate_once()?;
+
+    let other: Value = args.req(0)?;
+    let other_df = match other.value {
+        UntaggedValue::DataFrame(PolarsData::EagerDataFrame(df)) => df,
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Incorrect type",
+                "expected a dataframe",
+                &other.tag.span,
+            ))
+        }
+    };
+
+    let (df, df_tag) = NuDataFrame::try_from_stream(&mut args.input, &tag.span)?;
+
+    let res = df
+        .as_ref()
+        .vstack(other_df.as_ref())
+        .map_err(|e| parse_polars_error::<&str>(&e, &tag.span, None))?;
+
+    let df = NuDataFrame::new(res);
+
+    Ok(OutputStream::one(df.into_value(df_tag)))
+}
@@ -0,0 +1,103 @@
+use crate::{commands::dataframe::utils::parse_polars_error, prelude::*};
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{dataframe::NuDataFrame, Signature};
+use polars::prelude::{AnyValue, DataFrame as PolarsDataFrame, NamedFrom, Series};
+
+pub struct DataFrame;
+
+impl WholeStreamCommand for DataFrame {
+    fn name(&self) -> &str {
+        "dataframe describe"
+    }
+
+    fn usage(&self) -> &str {
+        "[DataFrame] Returns a summary frame with count, mean, std, min, quantiles and max per column"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("dataframe describe")
+    }
+
+    fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        command(args)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Returns a summary of a dataframe",
+            example: "[[a b]; [1 2] [3 4]] | dataframe to-df | dataframe describe",
+            result: None,
+        }]
+    }
+}
+
+const STATS: &[&str] = &["count", "null_count", "mean", "std", "min", "25%", "50%", "75%", "max"];
+
+fn command(mut args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let tag = args.call_info.name_tag.clone();
+
+    let (df, df_tag) = NuDataFrame::try_from_stream(&mut args.input, &tag.span)?;
+    let polars_df = df.as_ref();
+
+    let mut columns: Vec<Series> = vec![Series::new("statistic", STATS)];
+
+    for column_name in polars_df.get_column_names() {
+        let column = polars_df
+            .column(column_name)
+            .map_err(|e| parse_polars_error::<&str>(&e, &tag.span, None))?;
+
+        let values: Vec<f64> = STATS
+            .iter()
+            .map(|stat| describe_stat(column, stat))
+            .collect();
+
+        columns.push(Series::new(column_name, values));
+    }
+
+    let res = PolarsDataFrame::new(columns)
+        .map_err(|e| parse_polars_error::<&str>(&e, &tag.span, None))?;
+
+    let df = NuDataFrame::new(res);
+
+    Ok(OutputStream::one(df.into_value(df_tag)))
+}
+
+fn describe_stat(column: &Series, stat: &str) -> f64 {
+    match stat {
+        "count" => column.len() as f64,
+        "null_count" => column.null_count() as f64,
+        "mean" => column.mean().unwrap_or(f64::NAN),
+        "std" => any_value_as_f64(column.std_as_series().get(0)),
+        "min" => any_value_as_f64(column.min_as_series().get(0)),
+        "max" => any_value_as_f64(column.max_as_series().get(0)),
+        "25%" => quantile_as_f64(column, 0.25),
+        "50%" => quantile_as_f64(column, 0.5),
+        "75%" => quantile_as_f64(column, 0.75),
+        _ => f64::NAN,
+    }
+}
+
+fn quantile_as_f64(column: &Series, quantile: f64) -> f64 {
+    column
+        .quantile_as_series(quantile)
+        .ok()
+        .map(|s| any_value_as_f64(s.get(0)))
+        .unwrap_or(f64::NAN)
+}
+
+/// Polars' numeric reducers (`mean`/`std`/`min`/`max`/`quantile`) return a
+/// single-value `Series` whose dtype mirrors the source column, so pulling
+/// a plain `f64` back out means matching the handful of numeric `AnyValue`
+/// variants `describe` can encounter instead of assuming one dtype.
+fn any_value_as_f64(value: AnyValue) -> f64 {
+    match value {
+        AnyValue::Float64(v) => v,
+        AnyValue::Float32(v) => v as f64,
+        AnyValue::Int64(v) => v as f64,
+        AnyValue::Int32(v) => v as f64,
+        AnyValue::UInt64(v) => v as f64,
+        AnyValue::UInt32(v) => v as f64,
+        _ => f64::NAN,
+    }
+}
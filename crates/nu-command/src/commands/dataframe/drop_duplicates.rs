@@ -0,0 +1,75 @@
+use crate::{commands::dataframe::utils::parse_polars_error, prelude::*};
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{dataframe::NuDataFrame, Signature, SyntaxShape};
+
+pub struct DataFrame;
+
+#[derive(Deserialize)]
+struct Arguments {
+    rest: Vec<String>,
+    maintain_order: bool,
+}
+
+impl WholeStreamCommand for DataFrame {
+    fn name(&self) -> &str {
+        "dataframe drop-duplicates"
+    }
+
+    fn usage(&self) -> &str {
+        "[DataFrame] Drops duplicate rows from the dataframe"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("dataframe drop-duplicates")
+            .rest(
+                SyntaxShape::String,
+                "column names to consider when looking for duplicates. Defaults to all columns",
+            )
+            .switch(
+                "maintain_order",
+                "keep the original row order of the surviving rows",
+                Some('m'),
+            )
+    }
+
+    fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        command(args)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Drops duplicate rows from the dataframe",
+            example: "[[a b]; [1 2] [1 2] [3 4]] | dataframe to-df | dataframe drop-duplicates",
+            result: None,
+        }]
+    }
+}
+
+fn command(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let tag = args.call_info.name_tag.clone();
+    let (
+        Arguments {
+            rest: columns,
+            maintain_order,
+        },
+        mut input,
+    ) = args.process()?;
+
+    let (df, df_tag) = NuDataFrame::try_from_stream(&mut input, &tag.span)?;
+
+    let subset = if columns.is_empty() {
+        None
+    } else {
+        Some(columns)
+    };
+
+    let res = df
+        .as_ref()
+        .drop_duplicates(maintain_order, subset.as_deref())
+        .map_err(|e| parse_polars_error::<&str>(&e, &tag.span, None))?;
+
+    let df = NuDataFrame::new(res);
+
+    Ok(OutputStream::one(df.into_value(df_tag)))
+}
@@ -0,0 +1,62 @@
+use crate::{commands::dataframe::utils::parse_polars_error, prelude::*};
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{dataframe::NuDataFrame, Signature, SyntaxShape};
+
+pub struct DataFrame;
+
+#[derive(Deserialize)]
+struct Arguments {
+    rest: Vec<String>,
+}
+
+impl WholeStreamCommand for DataFrame {
+    fn name(&self) -> &str {
+        "dataframe drop-nulls"
+    }
+
+    fn usage(&self) -> &str {
+        "[DataFrame] Drops rows that contain a null value"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("dataframe drop-nulls").rest(
+            SyntaxShape::String,
+            "column names to consider when looking for nulls. Defaults to all columns",
+        )
+    }
+
+    fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        command(args)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Drops rows that contain a null value",
+            example: "[[a]; [1] [$nothing] [3]] | dataframe to-df | dataframe drop-nulls",
+            result: None,
+        }]
+    }
+}
+
+fn command(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let tag = args.call_info.name_tag.clone();
+    let (Arguments { rest: columns }, mut input) = args.process()?;
+
+    let (df, df_tag) = NuDataFrame::try_from_stream(&mut input, &tag.span)?;
+
+    let subset = if columns.is_empty() {
+        None
+    } else {
+        Some(columns)
+    };
+
+    let res = df
+        .as_ref()
+        .drop_nulls(subset.as_deref())
+        .map_err(|e| parse_polars_error::<&str>(&e, &tag.span, None))?;
+
+    let df = NuDataFrame::new(res);
+
+    Ok(OutputStream::one(df.into_value(df_tag)))
+}
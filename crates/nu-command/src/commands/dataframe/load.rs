@@ -1,15 +1,19 @@
+use std::io::Cursor;
 use std::path::PathBuf;
 
 use crate::prelude::*;
 use nu_engine::{EvaluatedCommandArgs, WholeStreamCommand};
 use nu_errors::ShellError;
 use nu_protocol::{
-    dataframe::{NuDataFrame, PolarsData},
+    dataframe::{NuDataFrame, NuLazyFrame, PolarsData},
     Primitive, Signature, SyntaxShape, UntaggedValue, Value,
 };
 
 use nu_source::Tagged;
-use polars::prelude::{CsvReader, JsonReader, ParquetReader, SerReader};
+use polars::prelude::{
+    CsvReader, DataType, Field, IntoLazy, IpcReader, JsonFormat, JsonReader, LazyCsvReader,
+    LazyFileListReader, LazyFrame, ParquetReader, Schema, ScanArgsParquet, SerReader,
+};
 use std::fs::File;
 
 pub struct DataFrame;
@@ -28,7 +32,7 @@ impl WholeStreamCommand for DataFrame {
             .required(
                 "file",
                 SyntaxShape::FilePath,
-                "the file path to load values from",
+                "the file path to load values from, or - to read from the pipeline",
             )
             .named(
                 "delimiter",
@@ -59,6 +63,20 @@ impl WholeStreamCommand for DataFrame {
                 "Columns to be selected from csv file. CSV file",
                 None,
             )
+            .named(
+                "dtypes",
+                SyntaxShape::Any,
+                "a record mapping column name to type name (int, float, str, date or bool), \
+                 overriding schema inference for the listed columns. CSV file",
+                None,
+            )
+            .switch(
+                "lazy",
+                "Creates a lazy-backed dataframe that defers reading csv and parquet files \
+                 (json and arrow files are read eagerly and then wrapped). `pls where` and \
+                 `pls save` accept only eager dataframes and reject a lazy one",
+                Some('l'),
+            )
     }
 
     fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
@@ -66,54 +84,117 @@ impl WholeStreamCommand for DataFrame {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Takes a file name and creates a dataframe",
-            example: "pls load test.csv",
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Takes a file name and creates a dataframe",
+                example: "pls load test.csv",
+                result: None,
+            },
+            Example {
+                description: "Overrides schema inference for the zip column",
+                example: "pls load test.csv --dtypes {zip: str}",
+                result: None,
+            },
+            Example {
+                description: "Loads a dataframe from piped output instead of a file",
+                example: "open -r test.csv | pls load -",
+                result: None,
+            },
+        ]
     }
 }
 
+enum FileKind {
+    Csv,
+    Parquet,
+    Json,
+    NdJson,
+    Arrow,
+}
+
 fn command(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let tag = args.call_info.name_tag.clone();
-    let args = args.evaluate_once()?;
+    let mut args = args.evaluate_once()?;
     let file: Tagged<PathBuf> = args.req(0)?;
+    let lazy = args.has_flag("lazy");
+    let from_stdin = file.item.to_str() == Some("-");
 
-    let df = match file.item().extension() {
-        Some(e) => match e.to_str() {
-            Some("csv") => from_csv(args),
-            Some("parquet") => from_parquet(args),
-            Some("json") => from_json(args),
-            _ => Err(ShellError::labeled_error(
-                "Error with file",
-                "Not a csv, parquet or json file",
-                &file.tag,
-            )),
-        },
-        None => Err(ShellError::labeled_error(
-            "Error with file",
-            "File without extension",
-            &file.tag,
-        )),
-    }?;
+    let kind = if from_stdin {
+        // No extension to dispatch on when reading from the pipeline: assume
+        // the most common piped tabular format, csv.
+        FileKind::Csv
+    } else {
+        match file.item().extension().and_then(|e| e.to_str()) {
+            Some("csv") => FileKind::Csv,
+            Some("parquet") => FileKind::Parquet,
+            Some("json") => FileKind::Json,
+            Some("ndjson") | Some("jsonl") => FileKind::NdJson,
+            Some("arrow") | Some("ipc") => FileKind::Arrow,
+            Some(_) => {
+                return Err(ShellError::labeled_error(
+                    "Error with file",
+                    "Not a csv, parquet, json, ndjson/jsonl or arrow/ipc file",
+                    &file.tag,
+                ))
+            }
+            None => {
+                return Err(ShellError::labeled_error(
+                    "Error with file",
+                    "File without extension",
+                    &file.tag,
+                ))
+            }
+        }
+    };
 
-    let file_name = match file.item.into_os_string().into_string() {
-        Ok(name) => name,
-        Err(e) => {
-            return Err(ShellError::labeled_error(
-                "Error with file name",
-                format!("{:?}", e),
-                &file.tag,
-            ))
+    let stdin_bytes = if from_stdin {
+        Some(read_stdin_bytes(&mut args, &tag)?)
+    } else {
+        None
+    };
+
+    let file_name = if from_stdin {
+        "stdin".to_string()
+    } else {
+        match file.item.clone().into_os_string().into_string() {
+            Ok(name) => name,
+            Err(e) => {
+                return Err(ShellError::labeled_error(
+                    "Error with file name",
+                    format!("{:?}", e),
+                    &file.tag,
+                ))
+            }
         }
     };
 
-    let init = InputStream::one(
+    let value = if lazy {
+        let lazy_frame = match kind {
+            FileKind::Csv => from_csv_lazy(args, stdin_bytes),
+            FileKind::Parquet => from_parquet_lazy(args),
+            FileKind::Json => from_json_lazy(args, JsonFormat::Json),
+            FileKind::NdJson => from_json_lazy(args, JsonFormat::JsonLines),
+            FileKind::Arrow => from_ipc(args).map(IntoLazy::lazy),
+        }?;
+
+        UntaggedValue::DataFrame(PolarsData::LazyFrame(NuLazyFrame::new_with_name(
+            lazy_frame, file_name,
+        )))
+    } else {
+        let df = match kind {
+            FileKind::Csv => from_csv(args, stdin_bytes),
+            FileKind::Parquet => from_parquet(args),
+            FileKind::Json => from_json(args, JsonFormat::Json),
+            FileKind::NdJson => from_json(args, JsonFormat::JsonLines),
+            FileKind::Arrow => from_ipc(args),
+        }?;
+
         UntaggedValue::DataFrame(PolarsData::EagerDataFrame(NuDataFrame::new_with_name(
             df, file_name,
         )))
-        .into_value(&tag),
-    );
+    };
+
+    let init = InputStream::one(value.into_value(&tag));
 
     Ok(init.to_output_stream())
 }
@@ -131,31 +212,77 @@ fn from_parquet(args: EvaluatedCommandArgs) -> Result<polars::prelude::DataFrame
         .map_err(|e| ShellError::labeled_error("Error with file", format!("{:?}", e), &file.tag))
 }
 
-fn from_json(args: EvaluatedCommandArgs) -> Result<polars::prelude::DataFrame, ShellError> {
+fn from_ipc(args: EvaluatedCommandArgs) -> Result<polars::prelude::DataFrame, ShellError> {
+    let file: Tagged<PathBuf> = args.req(0)?;
+
+    let r = File::open(&file.item)
+        .map_err(|e| ShellError::labeled_error("Error with file", format!("{:?}", e), &file.tag))?;
+
+    let reader = IpcReader::new(r);
+
+    reader
+        .finish()
+        .map_err(|e| ShellError::labeled_error("Error with file", format!("{:?}", e), &file.tag))
+}
+
+fn from_json(
+    args: EvaluatedCommandArgs,
+    format: JsonFormat,
+) -> Result<polars::prelude::DataFrame, ShellError> {
     let file: Tagged<PathBuf> = args.req(0)?;
 
     let r = File::open(&file.item)
         .map_err(|e| ShellError::labeled_error("Error with file", format!("{:?}", e), &file.tag))?;
 
-    let reader = JsonReader::new(r);
+    let reader = JsonReader::new(r).with_json_format(format);
 
     reader
         .finish()
         .map_err(|e| ShellError::labeled_error("Error with file", format!("{:?}", e), &file.tag))
 }
 
-fn from_csv(args: EvaluatedCommandArgs) -> Result<polars::prelude::DataFrame, ShellError> {
+fn from_csv(
+    args: EvaluatedCommandArgs,
+    stdin_bytes: Option<Vec<u8>>,
+) -> Result<polars::prelude::DataFrame, ShellError> {
     let file: Tagged<PathBuf> = args.req(0)?;
+    let dtypes: Option<Value> = args.get_flag("dtypes")?;
+    let schema = dtypes.as_ref().map(build_dtypes_schema).transpose()?;
+
+    let csv_reader = match stdin_bytes {
+        Some(bytes) => CsvReader::new(Cursor::new(bytes)),
+        None => CsvReader::from_path(&file.item).map_err(|e| {
+            ShellError::labeled_error("Unable to parse file", format!("{}", e), &file.tag)
+        })?,
+    };
+
+    let csv_reader = match &schema {
+        None => csv_reader,
+        Some(schema) => csv_reader.with_dtypes(Some(schema)),
+    };
+
+    let csv_reader = configure_csv_reader(csv_reader, &args)?;
+
+    match csv_reader.finish() {
+        Ok(csv_reader) => Ok(csv_reader),
+        Err(e) => Err(ShellError::labeled_error(
+            "Error while parsing dataframe",
+            format!("{}", e),
+            &file.tag,
+        )),
+    }
+}
+
+fn configure_csv_reader<R: std::io::Read>(
+    csv_reader: CsvReader<R>,
+    args: &EvaluatedCommandArgs,
+) -> Result<CsvReader<R>, ShellError> {
     let delimiter: Option<Tagged<String>> = args.get_flag("delimiter")?;
     let no_header: bool = args.has_flag("no_header");
     let infer_schema: Option<Tagged<usize>> = args.get_flag("infer_schema")?;
     let skip_rows: Option<Tagged<usize>> = args.get_flag("skip_rows")?;
     let columns: Option<Vec<Value>> = args.get_flag("columns")?;
 
-    let csv_reader = CsvReader::from_path(&file.item).map_err(|e| {
-        ShellError::labeled_error("Unable to parse file", format!("{}", e), &file.tag)
-    })?;
-
     let csv_reader = match delimiter {
         None => csv_reader,
         Some(d) => {
@@ -210,12 +337,176 @@ fn from_csv(args: EvaluatedCommandArgs) -> Result<polars::prelude::DataFrame, Sh
         }
     };
 
-    match csv_reader.finish() {
-        Ok(csv_reader) => Ok(csv_reader),
-        Err(e) => Err(ShellError::labeled_error(
-            "Error while parsing dataframe",
-            format!("{}", e),
-            &file.tag,
-        )),
+    Ok(csv_reader)
+}
+
+/// Translates a `{column: dtype, ...}` record into a polars `Schema`, so
+/// `--dtypes` can override inference for columns it misreads (zip codes,
+/// IDs, anything that looks numeric but isn't meant to be).
+fn build_dtypes_schema(value: &Value) -> Result<Schema, ShellError> {
+    let row = match &value.value {
+        UntaggedValue::Row(row) => row,
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Incorrect value for dtypes",
+                "expected a record mapping column name to type name",
+                &value.tag,
+            ))
+        }
+    };
+
+    let fields = row
+        .entries
+        .iter()
+        .map(|(name, dtype_value)| {
+            let dtype_name = dtype_value.as_string()?;
+            let dtype = match dtype_name.as_str() {
+                "int" => DataType::Int64,
+                "float" => DataType::Float64,
+                "str" => DataType::Utf8,
+                "date" => DataType::Date,
+                "bool" => DataType::Boolean,
+                _ => {
+                    return Err(ShellError::labeled_error(
+                        "Unknown dtype",
+                        "expected one of int, float, str, date or bool",
+                        &dtype_value.tag,
+                    ))
+                }
+            };
+            Ok(Field::new(name, dtype))
+        })
+        .collect::<Result<Vec<Field>, ShellError>>()?;
+
+    Ok(Schema::new(fields))
+}
+
+/// Reads the upstream pipeline into a single buffer for `pls load -`, so
+/// piped output can be loaded into a dataframe without going through a temp
+/// file first.
+fn read_stdin_bytes(args: &mut EvaluatedCommandArgs, tag: &Tag) -> Result<Vec<u8>, ShellError> {
+    let mut bytes = Vec::new();
+    for value in args.input.by_ref() {
+        match value.value {
+            UntaggedValue::Primitive(Primitive::String(s)) => {
+                bytes.extend_from_slice(s.as_bytes());
+                bytes.push(b'\n');
+            }
+            UntaggedValue::Primitive(Primitive::Binary(b)) => bytes.extend_from_slice(&b),
+            _ => {
+                return Err(ShellError::labeled_error(
+                    "Incorrect stream input",
+                    "Expected string or binary data from the pipeline",
+                    tag,
+                ))
+            }
+        }
+    }
+    Ok(bytes)
+}
+
+fn from_parquet_lazy(args: EvaluatedCommandArgs) -> Result<LazyFrame, ShellError> {
+    let file: Tagged<PathBuf> = args.req(0)?;
+
+    let file_path = file.item.to_str().ok_or_else(|| {
+        ShellError::labeled_error("Error with file", "Path is not valid utf-8", &file.tag)
+    })?;
+
+    LazyFrame::scan_parquet(file_path, ScanArgsParquet::default())
+        .map_err(|e| ShellError::labeled_error("Error with file", format!("{:?}", e), &file.tag))
+}
+
+fn from_json_lazy(args: EvaluatedCommandArgs, format: JsonFormat) -> Result<LazyFrame, ShellError> {
+    // polars has no lazy-scanning json reader; fall back to the eager reader
+    // and wrap the in-memory frame in a `LazyFrame`; the read itself isn't deferred.
+    from_json(args, format).map(IntoLazy::lazy)
+}
+
+fn from_csv_lazy(
+    args: EvaluatedCommandArgs,
+    stdin_bytes: Option<Vec<u8>>,
+) -> Result<LazyFrame, ShellError> {
+    let file: Tagged<PathBuf> = args.req(0)?;
+
+    if stdin_bytes.is_some() {
+        // The lazy csv reader scans a path directly, so reading from the
+        // pipeline can only be done eagerly and then handed to the optimizer.
+        return from_csv(args, stdin_bytes).map(IntoLazy::lazy);
+    }
+
+    let dtypes: Option<Value> = args.get_flag("dtypes")?;
+    let schema = dtypes.as_ref().map(build_dtypes_schema).transpose()?;
+    let no_header: bool = args.has_flag("no_header");
+    let infer_schema: Option<Tagged<usize>> = args.get_flag("infer_schema")?;
+    let skip_rows: Option<Tagged<usize>> = args.get_flag("skip_rows")?;
+    let columns: Option<Vec<Value>> = args.get_flag("columns")?;
+    let delimiter: Option<Tagged<String>> = args.get_flag("delimiter")?;
+
+    let file_path = file.item.to_str().ok_or_else(|| {
+        ShellError::labeled_error("Error with file", "Path is not valid utf-8", &file.tag)
+    })?;
+
+    let csv_reader = LazyCsvReader::new(file_path).has_header(!no_header);
+
+    let csv_reader = match schema {
+        None => csv_reader,
+        Some(schema) => csv_reader.with_dtype_overwrite(Some(&schema)),
+    };
+
+    let csv_reader = match delimiter {
+        None => csv_reader,
+        Some(d) => {
+            if d.item.len() != 1 {
+                return Err(ShellError::labeled_error(
+                    "Incorrect delimiter",
+                    "Delimiter has to be one char",
+                    &d.tag,
+                ));
+            } else {
+                let delimiter = match d.item.chars().nth(0) {
+                    Some(d) => d as u8,
+                    None => unreachable!(),
+                };
+                csv_reader.with_delimiter(delimiter)
+            }
+        }
+    };
+
+    let csv_reader = match infer_schema {
+        None => csv_reader,
+        Some(r) => csv_reader.with_infer_schema_length(Some(r.item)),
+    };
+
+    let csv_reader = match skip_rows {
+        None => csv_reader,
+        Some(r) => csv_reader.with_skip_rows(r.item),
+    };
+
+    let lazy_frame = csv_reader
+        .finish()
+        .map_err(|e| ShellError::labeled_error("Unable to parse file", format!("{}", e), &file.tag))?;
+
+    // Projection pushdown for the lazy csv reader happens through a `select`
+    // fused onto the scan rather than a reader-level option, so apply the
+    // requested columns the same way a later `select` in the pipeline would.
+    match columns {
+        None => Ok(lazy_frame),
+        Some(c) => {
+            let columns = c
+                .into_iter()
+                .map(|value| match value.value {
+                    UntaggedValue::Primitive(Primitive::String(s)) => {
+                        Ok(polars::prelude::col(&s))
+                    }
+                    _ => Err(ShellError::labeled_error(
+                        "Incorrect type for column",
+                        "Only string as columns",
+                        &value.tag,
+                    )),
+                })
+                .collect::<Result<Vec<_>, ShellError>>()?;
+
+            Ok(lazy_frame.select(&columns))
+        }
     }
 }
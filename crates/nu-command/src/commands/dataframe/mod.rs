@@ -0,0 +1,23 @@
+mod concatenate;
+mod describe;
+mod drop_duplicates;
+mod drop_nulls;
+mod load;
+mod save;
+pub mod series;
+pub(crate) mod utils;
+mod where_;
+
+pub use concatenate::DataFrame as DataFrameConcatenate;
+pub use describe::DataFrame as DataFrameDescribe;
+pub use drop_duplicates::DataFrame as DataFrameDropDuplicates;
+pub use drop_nulls::DataFrame as DataFrameDropNulls;
+pub use load::DataFrame as DataFrameLoad;
+pub use save::DataFrame as DataFrameSave;
+pub use where_::DataFrame as DataFrameWhere;
+
+pub use series::{
+    DataFrameArgMax, DataFrameArgMin, DataFrameArgSort, DataFrameArgTrue, DataFrameArgUnique,
+    DataFrameContains, DataFrameCountMatches, DataFrameCumulative, DataFrameExtract,
+    DataFrameReplace, DataFrameReplaceAll, DataFrameSplit, DataFrameUnique, DataFrameValueCounts,
+};
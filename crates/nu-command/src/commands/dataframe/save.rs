@@ -0,0 +1,147 @@
+use std::path::PathBuf;
+
+use crate::prelude::*;
+use nu_engine::{EvaluatedCommandArgs, WholeStreamCommand};
+use nu_errors::ShellError;
+use nu_protocol::{
+    dataframe::{NuDataFrame, PolarsData},
+    Signature, SyntaxShape, UntaggedValue,
+};
+
+use nu_source::Tagged;
+use nu_stream::ToActionStream;
+use polars::prelude::{CsvWriter, IpcWriter, JsonWriter, ParquetWriter, SerWriter};
+use std::fs::File;
+
+pub struct DataFrame;
+
+impl WholeStreamCommand for DataFrame {
+    fn name(&self) -> &str {
+        "pls save"
+    }
+
+    fn usage(&self) -> &str {
+        "Saves a dataframe to a csv, parquet, json or arrow file"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("pls save")
+            .required(
+                "file",
+                SyntaxShape::FilePath,
+                "the file path to save the dataframe to",
+            )
+            .named(
+                "delimiter",
+                SyntaxShape::String,
+                "file delimiter character. CSV file",
+                Some('d'),
+            )
+            .switch(
+                "no_header",
+                "Indicates if file should not have a header. CSV file",
+                None,
+            )
+    }
+
+    fn run_with_actions(&self, args: CommandArgs) -> Result<ActionStream, ShellError> {
+        command(args)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Saves a dataframe as a parquet file",
+            example: "pls load big.csv | pls save big.parquet",
+            result: None,
+        }]
+    }
+}
+
+enum FileKind {
+    Csv,
+    Parquet,
+    Json,
+    Arrow,
+}
+
+fn command(args: CommandArgs) -> Result<ActionStream, ShellError> {
+    let tag = args.call_info.name_tag.clone();
+    let mut args = args.evaluate_once()?;
+    let file: Tagged<PathBuf> = args.req(0)?;
+
+    let kind = match file.item().extension().and_then(|e| e.to_str()) {
+        Some("csv") => FileKind::Csv,
+        Some("parquet") => FileKind::Parquet,
+        Some("json") | Some("ndjson") => FileKind::Json,
+        Some("arrow") | Some("ipc") => FileKind::Arrow,
+        Some(_) => {
+            return Err(ShellError::labeled_error(
+                "Error with file",
+                "Not a csv, parquet, json or arrow file",
+                &file.tag,
+            ))
+        }
+        None => {
+            return Err(ShellError::labeled_error(
+                "Error with file",
+                "File without extension",
+                &file.tag,
+            ))
+        }
+    };
+
+    let df = args
+        .input
+        .next()
+        .and_then(|value| match value.value {
+            UntaggedValue::DataFrame(PolarsData::EagerDataFrame(nu)) => Some(nu),
+            _ => None,
+        })
+        .ok_or_else(|| {
+            ShellError::labeled_error(
+                "Incorrect stream input",
+                "Expected dataframe in stream",
+                &tag,
+            )
+        })?;
+
+    let mut file_handle = File::create(&file.item).map_err(|e| {
+        ShellError::labeled_error("Error with file", format!("{:?}", e), &file.tag)
+    })?;
+
+    let mut polars_df = df.as_ref().clone();
+
+    match kind {
+        FileKind::Csv => {
+            let delimiter: Option<Tagged<String>> = args.get_flag("delimiter")?;
+            let no_header: bool = args.has_flag("no_header");
+
+            let mut writer = CsvWriter::new(&mut file_handle).has_header(!no_header);
+
+            if let Some(d) = delimiter {
+                if d.item.len() != 1 {
+                    return Err(ShellError::labeled_error(
+                        "Incorrect delimiter",
+                        "Delimiter has to be one char",
+                        &d.tag,
+                    ));
+                }
+
+                let delimiter = match d.item.chars().nth(0) {
+                    Some(d) => d as u8,
+                    None => unreachable!(),
+                };
+
+                writer = writer.with_delimiter(delimiter);
+            }
+
+            writer.finish(&mut polars_df)
+        }
+        FileKind::Parquet => ParquetWriter::new(&mut file_handle).finish(&mut polars_df),
+        FileKind::Json => JsonWriter::new(&mut file_handle).finish(&mut polars_df),
+        FileKind::Arrow => IpcWriter::new(&mut file_handle).finish(&mut polars_df),
+    }
+    .map_err(|e| ShellError::labeled_error("Error saving dataframe", format!("{}", e), &file.tag))?;
+
+    Ok(OutputStream::empty().to_action_stream())
+}
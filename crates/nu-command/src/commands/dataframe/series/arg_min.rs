@@ -0,0 +1,47 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{dataframe::NuDataFrame, Signature};
+use polars::prelude::Series;
+
+pub struct DataFrame;
+
+impl WholeStreamCommand for DataFrame {
+    fn name(&self) -> &str {
+        "dataframe arg-min"
+    }
+
+    fn usage(&self) -> &str {
+        "[Series] Returns the index of the minimum value in the series"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("dataframe arg-min")
+    }
+
+    fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        command(args)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Returns the index of the minimum value",
+            example: "[1 3 2] | dataframe to-df | dataframe arg-min",
+            result: None,
+        }]
+    }
+}
+
+fn command(mut args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let tag = args.call_info.name_tag.clone();
+
+    let (df, df_tag) = NuDataFrame::try_from_stream(&mut args.input, &tag.span)?;
+
+    let series = df.as_series(&df_tag.span)?;
+    let index = series.arg_min().map(|idx| idx as u32);
+    let res = Series::new("arg_min", &[index]);
+
+    let df = NuDataFrame::try_from_series(vec![res], &tag.span)?;
+
+    Ok(OutputStream::one(df.into_value(tag)))
+}
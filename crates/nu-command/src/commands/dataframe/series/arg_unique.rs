@@ -0,0 +1,50 @@
+use crate::{commands::dataframe::utils::parse_polars_error, prelude::*};
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{dataframe::NuDataFrame, Signature};
+use polars::prelude::IntoSeries;
+
+pub struct DataFrame;
+
+impl WholeStreamCommand for DataFrame {
+    fn name(&self) -> &str {
+        "dataframe arg-unique"
+    }
+
+    fn usage(&self) -> &str {
+        "[Series] Returns indexes for the unique values in the series"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("dataframe arg-unique")
+    }
+
+    fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        command(args)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Returns indexes for the unique values in the series",
+            example: "[1 2 2 3 3] | dataframe to-df | dataframe arg-unique",
+            result: None,
+        }]
+    }
+}
+
+fn command(mut args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let tag = args.call_info.name_tag.clone();
+
+    let (df, df_tag) = NuDataFrame::try_from_stream(&mut args.input, &tag.span)?;
+
+    let series = df.as_series(&df_tag.span)?;
+    let mut res = series
+        .arg_unique()
+        .map_err(|e| parse_polars_error::<&str>(&e, &tag.span, None))?
+        .into_series();
+    res.rename("arg_unique");
+
+    let df = NuDataFrame::try_from_series(vec![res], &tag.span)?;
+
+    Ok(OutputStream::one(df.into_value(tag)))
+}
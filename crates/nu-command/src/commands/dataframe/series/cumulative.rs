@@ -0,0 +1,68 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{dataframe::NuDataFrame, Signature, SyntaxShape};
+use nu_source::Tagged;
+
+pub struct DataFrame;
+
+impl WholeStreamCommand for DataFrame {
+    fn name(&self) -> &str {
+        "dataframe cumulative"
+    }
+
+    fn usage(&self) -> &str {
+        "[Series] Returns a new series with the cumulative sum, min, max or product"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("dataframe cumulative")
+            .required_named(
+                "type",
+                SyntaxShape::String,
+                "cumulative operation to apply: sum, min, max or prod",
+                Some('t'),
+            )
+            .switch("reverse", "apply the operation in reverse order", Some('r'))
+    }
+
+    fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        command(args)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Returns the cumulative sum of a series",
+            example: "[1 2 3 4] | dataframe to-df | dataframe cumulative -t sum",
+            result: None,
+        }]
+    }
+}
+
+fn command(mut args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let tag = args.call_info.name_tag.clone();
+    let cum_type: Tagged<String> = args.req_named("type")?;
+    let reverse = args.has_flag("reverse");
+
+    let (df, df_tag) = NuDataFrame::try_from_stream(&mut args.input, &tag.span)?;
+
+    let series = df.as_series(&df_tag.span)?;
+    let mut res = match cum_type.item.as_str() {
+        "sum" => series.cumsum(reverse),
+        "min" => series.cummin(reverse),
+        "max" => series.cummax(reverse),
+        "prod" => series.cumprod(reverse),
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Incorrect cumulative type",
+                "expected one of sum, min, max or prod",
+                &cum_type.tag.span,
+            ))
+        }
+    };
+    res.rename(series.name());
+
+    let df = NuDataFrame::try_from_series(vec![res], &tag.span)?;
+
+    Ok(OutputStream::one(df.into_value(tag)))
+}
@@ -0,0 +1,73 @@
+use crate::{commands::dataframe::utils::parse_polars_error, prelude::*};
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{dataframe::NuDataFrame, Signature, SyntaxShape};
+use nu_source::Tagged;
+use polars::prelude::IntoSeries;
+
+pub struct DataFrame;
+
+impl WholeStreamCommand for DataFrame {
+    fn name(&self) -> &str {
+        "dataframe extract"
+    }
+
+    fn usage(&self) -> &str {
+        "[Series] Extracts a regex capture group into a new string series"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("dataframe extract")
+            .required_named(
+                "pattern",
+                SyntaxShape::String,
+                "Regex pattern with at least one capture group",
+                Some('p'),
+            )
+            .named(
+                "group",
+                SyntaxShape::Int,
+                "capture group index to extract. Defaults to 1",
+                Some('g'),
+            )
+    }
+
+    fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        command(args)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Extracts the digits from a string",
+            example: r#"[abc123 abc456] | dataframe to-df | dataframe extract -p '\D+(\d+)'"#,
+            result: None,
+        }]
+    }
+}
+
+fn command(mut args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let tag = args.call_info.name_tag.clone();
+    let pattern: Tagged<String> = args.req_named("pattern")?;
+    let group: Option<Tagged<usize>> = args.get_flag("group")?;
+    let group = group.map(|g| *g).unwrap_or(1);
+
+    let (df, df_tag) = NuDataFrame::try_from_stream(&mut args.input, &tag.span)?;
+
+    let series = df.as_series(&df_tag.span)?;
+    let chunked = series.utf8().map_err(|e| {
+        parse_polars_error::<&str>(
+            &e,
+            &df_tag.span,
+            Some("The extract command can only be used with string columns"),
+        )
+    })?;
+
+    let mut res = chunked
+        .extract(pattern.as_str(), group)
+        .map_err(|e| parse_polars_error::<&str>(&e, &tag.span, None))?;
+
+    res.rename(series.name());
+
+    let df = NuDataFrame::try_from_series(vec![res.into_series()], &tag.span)?;
+    Ok(OutputStream::one(df.into_value(df_tag)))
+}
@@ -0,0 +1,29 @@
+mod arg_max;
+mod arg_min;
+mod arg_sort;
+mod arg_true;
+mod arg_unique;
+mod contains;
+mod count_matches;
+mod cumulative;
+mod extract;
+mod replace;
+mod replace_all;
+mod split;
+mod unique;
+mod value_counts;
+
+pub use arg_max::DataFrame as DataFrameArgMax;
+pub use arg_min::DataFrame as DataFrameArgMin;
+pub use arg_sort::DataFrame as DataFrameArgSort;
+pub use arg_true::DataFrame as DataFrameArgTrue;
+pub use arg_unique::DataFrame as DataFrameArgUnique;
+pub use contains::DataFrame as DataFrameContains;
+pub use count_matches::DataFrame as DataFrameCountMatches;
+pub use cumulative::DataFrame as DataFrameCumulative;
+pub use extract::DataFrame as DataFrameExtract;
+pub use replace::DataFrame as DataFrameReplace;
+pub use replace_all::DataFrame as DataFrameReplaceAll;
+pub use split::DataFrame as DataFrameSplit;
+pub use unique::DataFrame as DataFrameUnique;
+pub use value_counts::DataFrame as DataFrameValueCounts;
@@ -13,11 +13,11 @@ impl WholeStreamCommand for DataFrame {
     }
 
     fn usage(&self) -> &str {
-        "[Series] Replace all (sub)strings by a regex pattern"
+        "[Series] Replace all (sub)strings matched by a regex pattern. Supports $1/named backreferences in the replacement string"
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("dataframe replace")
+        Signature::build("dataframe replace-all")
             .required_named(
                 "pattern",
                 SyntaxShape::String,
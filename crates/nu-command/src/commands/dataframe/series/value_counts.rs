@@ -0,0 +1,47 @@
+use crate::{commands::dataframe::utils::parse_polars_error, prelude::*};
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{dataframe::NuDataFrame, Signature};
+
+pub struct DataFrame;
+
+impl WholeStreamCommand for DataFrame {
+    fn name(&self) -> &str {
+        "dataframe value-counts"
+    }
+
+    fn usage(&self) -> &str {
+        "[Series] Returns a dataframe with the counts for unique values in the series"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("dataframe value-counts")
+    }
+
+    fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        command(args)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Returns the counts for unique values",
+            example: "[5 5 5 4 4 3] | dataframe to-df | dataframe value-counts",
+            result: None,
+        }]
+    }
+}
+
+fn command(mut args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let tag = args.call_info.name_tag.clone();
+
+    let (df, df_tag) = NuDataFrame::try_from_stream(&mut args.input, &tag.span)?;
+
+    let series = df.as_series(&df_tag.span)?;
+    let res = series
+        .value_counts()
+        .map_err(|e| parse_polars_error::<&str>(&e, &tag.span, None))?;
+
+    let df = NuDataFrame::new(res);
+
+    Ok(OutputStream::one(df.into_value(tag)))
+}
@@ -8,7 +8,7 @@ use nu_protocol::{
 };
 
 use super::utils::parse_polars_error;
-use polars::prelude::{ChunkCompare, Series};
+use polars::prelude::{BooleanChunked, ChunkCompare, Series};
 
 pub struct DataFrame;
 
@@ -52,18 +52,11 @@ fn command(args: CommandArgs) -> Result<OutputStream, ShellError> {
         .block
         .block
         .get(0)
-        .and_then(|group| {
-            group
-                .pipelines
-                .get(0)
-                .and_then(|v| v.list.get(0))
-                .and_then(|expr| match &expr {
-                    ClassifiedCommand::Expr(expr) => match &expr.as_ref().expr {
-                        Expression::Binary(expr) => Some(expr),
-                        _ => None,
-                    },
-                    _ => None,
-                })
+        .and_then(|group| group.pipelines.get(0))
+        .and_then(|pipeline| pipeline.list.get(0))
+        .and_then(|item| match item {
+            ClassifiedCommand::Expr(expr) => Some(expr.as_ref().clone()),
+            _ => None,
         })
         .ok_or(ShellError::labeled_error(
             "Expected a condition",
@@ -71,42 +64,7 @@ fn command(args: CommandArgs) -> Result<OutputStream, ShellError> {
             &tag.span,
         ))?;
 
-    let left_value = match &expression.left.expr {
-        Expression::FullColumnPath(p) => p.as_ref().tail.get(0),
-        _ => None,
-    }
-    .ok_or(ShellError::labeled_error(
-        "No column name",
-        "Not a column name found in left hand side of comparison",
-        &expression.left.span,
-    ))?;
-
-    let (col_name, col_name_span) = match &left_value.unspanned {
-        UnspannedPathMember::String(name) => Ok((name, &left_value.span)),
-        _ => Err(ShellError::labeled_error(
-            "No column name",
-            "Not a string as column name",
-            &left_value.span,
-        )),
-    }?;
-
-    let right_value = evaluate_baseline_expr(&expression.right, &args.args.context)?;
-    let right_condition = match &right_value.value {
-        UntaggedValue::Primitive(primitive) => Ok(primitive),
-        _ => Err(ShellError::labeled_error(
-            "Incorrect argument",
-            "Expected primitive values",
-            &right_value.tag.span,
-        )),
-    }?;
-
-    filter_dataframe(
-        args,
-        &col_name,
-        &col_name_span,
-        &right_condition,
-        &expression.op,
-    )
+    filter_dataframe(args, &expression)
 }
 
 macro_rules! comparison_arm {
@@ -143,10 +101,7 @@ macro_rules! comparison_arm {
 // polars operations
 fn filter_dataframe(
     mut args: EvaluatedCommandArgs,
-    col_name: &str,
-    col_name_span: &Span,
-    right_condition: &Primitive,
-    operator: &SpannedExpression,
+    condition: &SpannedExpression,
 ) -> Result<OutputStream, ShellError> {
     let df = args
         .input
@@ -161,47 +116,204 @@ fn filter_dataframe(
             &args.call_info.name_tag.span,
         ))?;
 
+    let mask = eval_condition(condition, &df, &args.args.context)?;
+
+    let res = df
+        .as_ref()
+        .filter(&mask)
+        .map_err(|e| parse_polars_error::<&str>(&e, &args.call_info.name_tag.span, None))?;
+
+    let value = Value {
+        value: UntaggedValue::DataFrame(PolarsData::EagerDataFrame(NuDataFrame::new(res))),
+        tag: args.call_info.name_tag.clone(),
+    };
+
+    Ok(OutputStream::one(value))
+}
+
+///Recursively walks a condition's `SpannedExpression` tree into a single
+///`BooleanChunked` mask. `Operator::And`/`Operator::Or` combine the masks
+///of their two sides bitwise; any other operator is a comparison leaf,
+///handled by `eval_comparison`. Parenthesized sub-conditions need no
+///special casing here, since by the time an expression reaches HIR its
+///grouping is already encoded as nested `Expression::Binary` nodes.
+fn eval_condition(
+    expr: &SpannedExpression,
+    df: &NuDataFrame,
+    ctx: &EvaluationContext,
+) -> Result<BooleanChunked, ShellError> {
+    let binary = match &expr.expr {
+        Expression::Binary(binary) => binary,
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Expected a condition",
+                "expected a comparison or a boolean combination of comparisons",
+                &expr.span,
+            ))
+        }
+    };
+
+    let op = match &binary.op.expr {
+        Expression::Literal(Literal::Operator(op)) => op,
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Incorrect argument",
+                "Expected operator",
+                &binary.op.span,
+            ))
+        }
+    };
+
+    match op {
+        Operator::And => {
+            Ok(eval_condition(&binary.left, df, ctx)? & eval_condition(&binary.right, df, ctx)?)
+        }
+        Operator::Or => {
+            Ok(eval_condition(&binary.left, df, ctx)? | eval_condition(&binary.right, df, ctx)?)
+        }
+        _ => eval_comparison(&binary.left, op, &binary.right, binary.op.span, df, ctx),
+    }
+}
+
+///Evaluates a single leaf comparison into a `BooleanChunked` mask:
+///`col_name <op> other_col` goes to `eval_column_comparison`, and
+///`col_name <op> <scalar>` goes through the existing `comparison_arm!` machinery.
+fn eval_comparison(
+    left: &SpannedExpression,
+    op: &Operator,
+    right: &SpannedExpression,
+    op_span: Span,
+    df: &NuDataFrame,
+    ctx: &EvaluationContext,
+) -> Result<BooleanChunked, ShellError> {
+    let left_value = match &left.expr {
+        Expression::FullColumnPath(p) => p.as_ref().tail.get(0),
+        _ => None,
+    }
+    .ok_or(ShellError::labeled_error(
+        "No column name",
+        "Not a column name found in left hand side of comparison",
+        &left.span,
+    ))?;
+
+    let (col_name, col_name_span) = match &left_value.unspanned {
+        UnspannedPathMember::String(name) => Ok((name, &left_value.span)),
+        _ => Err(ShellError::labeled_error(
+            "No column name",
+            "Not a string as column name",
+            &left_value.span,
+        )),
+    }?;
+
     let col = df
         .as_ref()
         .column(col_name)
         .map_err(|e| parse_polars_error::<&str>(&e, &col_name_span, None))?;
 
-    let op = match &operator.expr {
-        Expression::Literal(Literal::Operator(op)) => Ok(op),
+    if let Some(other_col_name) = column_name(right) {
+        let other_col = df
+            .as_ref()
+            .column(other_col_name)
+            .map_err(|e| parse_polars_error::<&str>(&e, &right.span, None))?;
+
+        return eval_column_comparison(col_name, col, other_col_name, other_col, op, op_span);
+    }
+
+    let right_value = evaluate_baseline_expr(right, ctx)?;
+    let right_condition = match &right_value.value {
+        UntaggedValue::Primitive(primitive) => Ok(primitive),
         _ => Err(ShellError::labeled_error(
             "Incorrect argument",
-            "Expected operator",
-            &operator.span,
+            "Expected primitive values",
+            &right_value.tag.span,
         )),
     }?;
 
-    let mask = match op {
-        Operator::Equal => comparison_arm!(Series::eq, col, right_condition, operator.span),
-        Operator::NotEqual => comparison_arm!(Series::neq, col, right_condition, operator.span),
-        Operator::LessThan => comparison_arm!(Series::lt, col, right_condition, operator.span),
+    match op {
+        Operator::Equal => comparison_arm!(Series::eq, col, right_condition, op_span),
+        Operator::NotEqual => comparison_arm!(Series::neq, col, right_condition, op_span),
+        Operator::LessThan => comparison_arm!(Series::lt, col, right_condition, op_span),
         Operator::LessThanOrEqual => {
-            comparison_arm!(Series::lt_eq, col, right_condition, operator.span)
+            comparison_arm!(Series::lt_eq, col, right_condition, op_span)
         }
-        Operator::GreaterThan => comparison_arm!(Series::gt, col, right_condition, operator.span),
+        Operator::GreaterThan => comparison_arm!(Series::gt, col, right_condition, op_span),
         Operator::GreaterThanOrEqual => {
-            comparison_arm!(Series::gt_eq, col, right_condition, operator.span)
+            comparison_arm!(Series::gt_eq, col, right_condition, op_span)
         }
         _ => Err(ShellError::labeled_error(
             "Incorrect operator",
             "Not implemented operator for dataframes filter",
-            &operator.span,
+            &op_span,
         )),
-    }?;
+    }
+}
 
-    let res = df
-        .as_ref()
-        .filter(&mask)
-        .map_err(|e| parse_polars_error::<&str>(&e, &args.call_info.name_tag.span, None))?;
+///Extracts the column name an expression refers to, if it is a bare
+///column path (e.g. the `col_b` in `col_a > col_b`), so `eval_comparison`
+///can tell a column-to-column comparison apart from a column-to-scalar
+///one before evaluating the right-hand side.
+fn column_name(expr: &SpannedExpression) -> Option<&str> {
+    match &expr.expr {
+        Expression::FullColumnPath(p) => match &p.as_ref().tail.get(0)?.unspanned {
+            UnspannedPathMember::String(name) => Some(name.as_str()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
 
-    let value = Value {
-        value: UntaggedValue::DataFrame(PolarsData::EagerDataFrame(NuDataFrame::new(res))),
-        tag: args.call_info.name_tag.clone(),
-    };
+///Compares two `Series` from the same dataframe directly, dispatching to
+///the `Series`-vs-`Series` `ChunkCompare` variants instead of the scalar
+///ones `comparison_arm!` uses. Polars' comparison ops assume matching
+///dtypes and lengths and panic otherwise, so both are checked up front to
+///turn that into a normal parse-time-style error instead.
+fn eval_column_comparison(
+    col_name: &str,
+    col: &Series,
+    other_col_name: &str,
+    other_col: &Series,
+    op: &Operator,
+    op_span: Span,
+) -> Result<BooleanChunked, ShellError> {
+    if col.dtype() != other_col.dtype() {
+        return Err(ShellError::labeled_error(
+            "Incompatible dtypes",
+            format!(
+                "cannot compare column '{}' ({}) with column '{}' ({})",
+                col_name,
+                col.dtype(),
+                other_col_name,
+                other_col.dtype()
+            ),
+            &op_span,
+        ));
+    }
 
-    Ok(OutputStream::one(value))
+    if col.len() != other_col.len() {
+        return Err(ShellError::labeled_error(
+            "Mismatched column lengths",
+            format!(
+                "column '{}' has {} rows but column '{}' has {} rows",
+                col_name,
+                col.len(),
+                other_col_name,
+                other_col.len()
+            ),
+            &op_span,
+        ));
+    }
+
+    match op {
+        Operator::Equal => Ok(Series::eq(col, other_col)),
+        Operator::NotEqual => Ok(Series::neq(col, other_col)),
+        Operator::LessThan => Ok(Series::lt(col, other_col)),
+        Operator::LessThanOrEqual => Ok(Series::lt_eq(col, other_col)),
+        Operator::GreaterThan => Ok(Series::gt(col, other_col)),
+        Operator::GreaterThanOrEqual => Ok(Series::gt_eq(col, other_col)),
+        _ => Err(ShellError::labeled_error(
+            "Incorrect operator",
+            "Not implemented operator for dataframes filter",
+            &op_span,
+        )),
+    }
 }
@@ -122,12 +122,14 @@ pub fn create_default_context(interactive: bool) -> Result<EvaluationContext, Bo
             whole_stream_command(Insert),
             whole_stream_command(Into),
             whole_stream_command(IntoBinary),
+            whole_stream_command(IntoFilepath),
             whole_stream_command(IntoInt),
             whole_stream_command(IntoString),
             whole_stream_command(SplitBy),
             // Row manipulation
             whole_stream_command(All),
             whole_stream_command(Any),
+            whole_stream_command(None),
             whole_stream_command(Reverse),
             whole_stream_command(Append),
             whole_stream_command(Prepend),
@@ -164,6 +166,7 @@ pub fn create_default_context(interactive: bool) -> Result<EvaluationContext, Bo
             whole_stream_command(Move),
             whole_stream_command(Merge),
             whole_stream_command(Shuffle),
+            whole_stream_command(Sample),
             whole_stream_command(Wrap),
             whole_stream_command(Pivot),
             whole_stream_command(Headers),
@@ -190,6 +193,8 @@ pub fn create_default_context(interactive: bool) -> Result<EvaluationContext, Bo
             whole_stream_command(MathSummation),
             whole_stream_command(MathVariance),
             whole_stream_command(MathProduct),
+            whole_stream_command(MathPercentile),
+            whole_stream_command(MathQuantile),
             whole_stream_command(MathRound),
             whole_stream_command(MathFloor),
             whole_stream_command(MathCeil),
@@ -292,6 +297,42 @@ pub fn create_default_context(interactive: bool) -> Result<EvaluationContext, Bo
             whole_stream_command(DataFramePivot),
             #[cfg(feature = "dataframe")]
             whole_stream_command(DataFrameWhere),
+            #[cfg(feature = "dataframe")]
+            whole_stream_command(DataFrameArgSort),
+            #[cfg(feature = "dataframe")]
+            whole_stream_command(DataFrameArgMax),
+            #[cfg(feature = "dataframe")]
+            whole_stream_command(DataFrameArgMin),
+            #[cfg(feature = "dataframe")]
+            whole_stream_command(DataFrameArgTrue),
+            #[cfg(feature = "dataframe")]
+            whole_stream_command(DataFrameArgUnique),
+            #[cfg(feature = "dataframe")]
+            whole_stream_command(DataFrameCumulative),
+            #[cfg(feature = "dataframe")]
+            whole_stream_command(DataFrameValueCounts),
+            #[cfg(feature = "dataframe")]
+            whole_stream_command(DataFrameContains),
+            #[cfg(feature = "dataframe")]
+            whole_stream_command(DataFrameCountMatches),
+            #[cfg(feature = "dataframe")]
+            whole_stream_command(DataFrameExtract),
+            #[cfg(feature = "dataframe")]
+            whole_stream_command(DataFrameReplace),
+            #[cfg(feature = "dataframe")]
+            whole_stream_command(DataFrameReplaceAll),
+            #[cfg(feature = "dataframe")]
+            whole_stream_command(DataFrameSplit),
+            #[cfg(feature = "dataframe")]
+            whole_stream_command(DataFrameUnique),
+            #[cfg(feature = "dataframe")]
+            whole_stream_command(DataFrameDropDuplicates),
+            #[cfg(feature = "dataframe")]
+            whole_stream_command(DataFrameDropNulls),
+            #[cfg(feature = "dataframe")]
+            whole_stream_command(DataFrameDescribe),
+            #[cfg(feature = "dataframe")]
+            whole_stream_command(DataFrameConcatenate),
         ]);
 
         #[cfg(feature = "clipboard-cli")]
@@ -0,0 +1,92 @@
+use crate::commands::math::utils::run_with_numerical_functions_on_stream;
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+
+pub struct SubCommand;
+
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "math round"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math round").named(
+            "precision",
+            SyntaxShape::Int,
+            "digits of precision to round to",
+            Some('p'),
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Applies a rounding function to a list of numbers"
+    }
+
+    fn run(&self, mut args: CommandArgs) -> Result<OutputStream, ShellError> {
+        let precision: Option<Tagged<usize>> = args.get_flag("precision")?;
+        let precision = precision.map(|p| *p).unwrap_or(0);
+
+        run_with_numerical_functions_on_stream(
+            RunnableContext::from_command_args(args),
+            round_big_int,
+            move |val| round_big_decimal(val, precision),
+            round_default,
+        )
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Apply the round function to a list of numbers",
+                example: "echo [1.5 2.3 -3.1] | math round",
+                result: Some(vec![
+                    UntaggedValue::int(2).into(),
+                    UntaggedValue::int(2).into(),
+                    UntaggedValue::int(-3).into(),
+                ]),
+            },
+            Example {
+                description: "Apply the round function with precision specified",
+                example: "echo [1.555 2.333 -3.111] | math round -p 2",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn round_big_int(val: BigInt) -> Value {
+    UntaggedValue::int(val).into()
+}
+
+fn round_big_decimal(val: BigDecimal, precision: usize) -> Value {
+    let rounded = val.round(precision as i64);
+    if precision == 0 {
+        let (int, _) = rounded.into_bigint_and_exponent();
+        UntaggedValue::int(int).into()
+    } else {
+        UntaggedValue::decimal(rounded).into()
+    }
+}
+
+fn round_default(_: UntaggedValue) -> Value {
+    UntaggedValue::Error(ShellError::unexpected(
+        "Only numerical values are supported",
+    ))
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
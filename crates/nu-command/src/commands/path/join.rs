@@ -0,0 +1,143 @@
+use super::PathSubcommandArguments;
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use std::path::PathBuf;
+
+pub struct PathJoin;
+
+#[derive(Deserialize)]
+struct PathJoinArguments {
+    rest: Vec<ColumnPath>,
+}
+
+impl PathSubcommandArguments for PathJoinArguments {
+    fn get_column_paths(&self) -> &Vec<ColumnPath> {
+        &self.rest
+    }
+}
+
+impl WholeStreamCommand for PathJoin {
+    fn name(&self) -> &str {
+        "path join"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("path join")
+            .rest(SyntaxShape::ColumnPath, "Optionally operate by column path")
+    }
+
+    fn usage(&self) -> &str {
+        "Join a list of parts back into a single path, or rebuild it from structured columns."
+    }
+
+    fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        let tag = args.call_info.name_tag.clone();
+        let (PathJoinArguments { rest }, input) = args.process()?;
+        let args = Arc::new(PathJoinArguments { rest });
+
+        if args.get_column_paths().is_empty() {
+            // The counterpart to `path split`'s unwrapped list output:
+            // collect the whole stream and join it into one path value.
+            let parts: Vec<Value> = input.collect();
+
+            let mut joined = PathBuf::new();
+            for part in &parts {
+                let part = part.as_string()?;
+                joined.push(part);
+            }
+
+            let value = UntaggedValue::filepath(joined).into_value(&tag);
+            Ok(OutputStream::one(ReturnSuccess::value(value)))
+        } else {
+            Ok(input
+                .map(move |v| {
+                    let mut ret = v;
+
+                    for path in args.get_column_paths() {
+                        let cloned_args = Arc::clone(&args);
+                        ret = ret.swap_data_by_column_path(
+                            path,
+                            Box::new(move |old| action(old, &cloned_args)),
+                        )?;
+                    }
+
+                    ReturnSuccess::value(ret)
+                })
+                .to_output_stream())
+        }
+    }
+
+    #[cfg(windows)]
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Join a list of parts into a path",
+            example: r"echo ['C:' 'Users' 'viking' 'spam.txt'] | path join",
+            result: None,
+        }]
+    }
+
+    #[cfg(not(windows))]
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Join a list of parts into a path",
+            example: r"echo ['/' 'home' 'viking' 'spam.txt'] | path join",
+            result: Some(vec![Value::from(UntaggedValue::filepath(
+                "/home/viking/spam.txt",
+            ))]),
+        }]
+    }
+}
+
+fn action(value: &Value, _args: &PathJoinArguments) -> Result<Value, ShellError> {
+    let tag = value.tag.clone();
+
+    if let UntaggedValue::Row(row) = &value.value {
+        let mut joined = PathBuf::new();
+
+        for column in &["parent", "prefix"] {
+            if let Some(part) = row.entries.get(*column) {
+                let part = part.as_string()?;
+                if !part.is_empty() {
+                    joined.push(part);
+                }
+            }
+        }
+
+        let stem = match row.entries.get("stem") {
+            Some(part) => part.as_string()?,
+            None => String::new(),
+        };
+        let extension = match row.entries.get("extension") {
+            Some(part) => part.as_string()?,
+            None => String::new(),
+        };
+        let filename = if extension.is_empty() {
+            stem
+        } else {
+            format!("{}.{}", stem, extension)
+        };
+        if !filename.is_empty() {
+            joined.push(filename);
+        }
+
+        return Ok(UntaggedValue::filepath(joined).into_value(&tag));
+    }
+
+    let part = value.as_string()?;
+    Ok(UntaggedValue::filepath(PathBuf::from(part)).into_value(&tag))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PathJoin;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(PathJoin {})
+    }
+}
@@ -0,0 +1,117 @@
+use super::{operate, PathSubcommandArguments};
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, Signature, SyntaxShape, UntaggedValue, Value};
+use std::path::Path;
+
+pub struct PathType;
+
+#[derive(Deserialize)]
+struct PathTypeArguments {
+    rest: Vec<ColumnPath>,
+}
+
+impl PathSubcommandArguments for PathTypeArguments {
+    fn get_column_paths(&self) -> &Vec<ColumnPath> {
+        &self.rest
+    }
+}
+
+impl WholeStreamCommand for PathType {
+    fn name(&self) -> &str {
+        "path type"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("path type")
+            .rest(SyntaxShape::ColumnPath, "Optionally operate by column path")
+    }
+
+    fn usage(&self) -> &str {
+        "Reports the kind of a path's node (file, dir, symlink, ...) without following symlinks, rather than a plain exists/not-exists boolean"
+    }
+
+    fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        let tag = args.call_info.name_tag.clone();
+        let (PathTypeArguments { rest }, input) = args.process()?;
+        let args = Arc::new(PathTypeArguments { rest });
+        operate(input, &action, tag.span, args)
+    }
+
+    #[cfg(windows)]
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Check the type of a path",
+            example: "echo 'C:\\Users\\joe\\todo.txt' | path type",
+            result: Some(vec![Value::from(UntaggedValue::string("none"))]),
+        }]
+    }
+
+    #[cfg(not(windows))]
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Check the type of a path",
+            example: "echo '/home/joe/todo.txt' | path type",
+            result: Some(vec![Value::from(UntaggedValue::string("none"))]),
+        }]
+    }
+}
+
+fn action(path: &Path, _args: &PathTypeArguments) -> UntaggedValue {
+    // `symlink_metadata` does not follow the final symlink, so a dangling
+    // link is reported as `symlink` rather than silently looking absent.
+    let kind = match std::fs::symlink_metadata(path) {
+        Ok(metadata) => {
+            let file_type = metadata.file_type();
+
+            if file_type.is_symlink() {
+                "symlink"
+            } else if file_type.is_dir() {
+                "dir"
+            } else if file_type.is_file() {
+                "file"
+            } else {
+                unix_only_kind(&file_type)
+            }
+        }
+        Err(_) => "none",
+    };
+
+    UntaggedValue::string(kind)
+}
+
+#[cfg(unix)]
+fn unix_only_kind(file_type: &std::fs::FileType) -> &'static str {
+    use std::os::unix::fs::FileTypeExt;
+
+    if file_type.is_fifo() {
+        "pipe"
+    } else if file_type.is_socket() {
+        "socket"
+    } else if file_type.is_block_device() {
+        "block"
+    } else if file_type.is_char_device() {
+        "char"
+    } else {
+        "unknown"
+    }
+}
+
+#[cfg(not(unix))]
+fn unix_only_kind(_file_type: &std::fs::FileType) -> &'static str {
+    "unknown"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PathType;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(PathType {})
+    }
+}
@@ -0,0 +1,90 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+
+// This only gives `append`'s streaming counterpart: the new row(s) are
+// chained in front of the still-lazy upstream stream rather than collected.
+// It does not change how `Value` represents a `Table` under the hood, so it
+// does not get the O(log n) structural sharing an `im::Vector`-backed
+// representation would provide; that's a cross-cutting change to
+// nu-protocol's value types this command doesn't make.
+#[derive(Deserialize)]
+struct Arguments {
+    value: Value,
+}
+
+pub struct Command;
+
+impl WholeStreamCommand for Command {
+    fn name(&self) -> &str {
+        "prepend"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("prepend").required(
+            "row value",
+            SyntaxShape::Any,
+            "the value of the row to prepend to the table",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Prepend a row to the table."
+    }
+
+    fn run_with_actions(&self, args: CommandArgs) -> Result<ActionStream, ShellError> {
+        let (Arguments { value }, input) = args.process()?;
+
+        // Checks if we are trying to prepend a row literal, unwrapping a
+        // single row into just that row, and a multi-row table into each
+        // of its rows so they are prepended individually.
+        let prepended: Vec<Value> = if let Value {
+            value: UntaggedValue::Table(values),
+            tag,
+        } = &value
+        {
+            if !values.is_empty() && values.iter().all(|row| row.is_row()) {
+                values
+                    .iter()
+                    .map(|row| row.value.clone().into_value(tag))
+                    .collect()
+            } else {
+                vec![value.clone()]
+            }
+        } else {
+            vec![value]
+        };
+
+        // The rest of the table is never collected up front: the prepended
+        // row(s) are chained in front of the still-lazy upstream stream.
+        Ok(prepended
+            .into_iter()
+            .chain(input)
+            .map(ReturnSuccess::value)
+            .to_output_stream_with_actions())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Add values to the beginning of the table",
+                example: "echo [1 2 3] | prepend 0",
+                result: Some(vec![
+                    UntaggedValue::int(0).into(),
+                    UntaggedValue::int(1).into(),
+                    UntaggedValue::int(2).into(),
+                    UntaggedValue::int(3).into(),
+                ]),
+            },
+            Example {
+                description: "Add row value to the beginning of the table",
+                example: "echo [[country]; [USA]] | prepend [[country]; [Ecuador]]",
+                result: Some(vec![
+                    row! { "country".into() => Value::from("Ecuador")},
+                    row! { "country".into() => Value::from("USA")},
+                ]),
+            },
+        ]
+    }
+}
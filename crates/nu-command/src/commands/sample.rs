@@ -0,0 +1,99 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, Value};
+use nu_source::Tagged;
+
+use rand::rngs::StdRng;
+use rand::{thread_rng, Rng, RngCore, SeedableRng};
+
+pub struct Sample;
+
+impl WholeStreamCommand for Sample {
+    fn name(&self) -> &str {
+        "sample"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("sample")
+            .required("n", SyntaxShape::Int, "number of rows to sample")
+            .named(
+                "seed",
+                SyntaxShape::Int,
+                "seed the random number generator for reproducible results",
+                None,
+            )
+            .switch(
+                "strict",
+                "error instead of returning all rows when fewer than `n` rows are available",
+                None,
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Draw a uniform random sample of rows without materializing the whole stream."
+    }
+
+    fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        sample(args)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Sample 2 rows from a list",
+            example: "echo [1 2 3 4 5] | sample 2",
+            result: None,
+        }]
+    }
+}
+
+fn sample(mut args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let n: Tagged<usize> = args.req(0)?;
+    let seed: Option<Tagged<u64>> = args.get_flag("seed")?;
+    let strict = args.has_flag("strict");
+
+    let mut rng: Box<dyn RngCore> = match seed {
+        Some(seed) => Box::new(StdRng::seed_from_u64(*seed)),
+        None => Box::new(thread_rng()),
+    };
+
+    let input = args.input;
+    let reservoir = reservoir_sample(input, n.item, rng.as_mut());
+
+    if strict && reservoir.len() < n.item {
+        return Err(ShellError::labeled_error(
+            "Not enough rows to sample",
+            format!(
+                "requested {} rows but only {} were available",
+                n.item,
+                reservoir.len()
+            ),
+            &n.tag,
+        ));
+    }
+
+    Ok((reservoir.into_iter().map(ReturnSuccess::value)).to_output_stream())
+}
+
+/// Algorithm R: keeps the first `n` items verbatim, then for each
+/// subsequent item at 0-based index `i`, draws `j` uniformly from
+/// `0..=i` and replaces `reservoir[j]` with the new item if `j < n`.
+/// This yields a uniform random `n`-subset of the whole stream in a
+/// single pass, without materializing it or shuffling it up front like
+/// `shuffle` does.
+fn reservoir_sample(input: InputStream, n: usize, rng: &mut dyn RngCore) -> Vec<Value> {
+    let mut reservoir: Vec<Value> = Vec::with_capacity(n);
+
+    for (i, value) in input.enumerate() {
+        if i < n {
+            reservoir.push(value);
+        } else {
+            let j = rng.gen_range(0..=i);
+            if j < n {
+                reservoir[j] = value;
+            }
+        }
+    }
+
+    reservoir
+}
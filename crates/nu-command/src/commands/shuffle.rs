@@ -1,10 +1,12 @@
 use crate::prelude::*;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
-use nu_protocol::{ReturnSuccess, Value};
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, Value};
+use nu_source::Tagged;
 
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
-use rand::thread_rng;
+use rand::{thread_rng, SeedableRng};
 
 pub struct Shuffle;
 
@@ -13,20 +15,33 @@ impl WholeStreamCommand for Shuffle {
         "shuffle"
     }
 
+    fn signature(&self) -> Signature {
+        Signature::build("shuffle").named(
+            "seed",
+            SyntaxShape::Int,
+            "seed the random number generator for reproducible results",
+            None,
+        )
+    }
+
     fn usage(&self) -> &str {
         "Shuffle rows randomly."
     }
 
-    fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
-        shuffle(args)
+    fn run(&self, mut args: CommandArgs) -> Result<OutputStream, ShellError> {
+        let seed: Option<Tagged<u64>> = args.get_flag("seed")?;
+        shuffle(args, seed.map(|s| *s))
     }
 }
 
-fn shuffle(args: CommandArgs) -> Result<OutputStream, ShellError> {
+fn shuffle(args: CommandArgs, seed: Option<u64>) -> Result<OutputStream, ShellError> {
     let input = args.input;
     let mut values: Vec<Value> = input.collect();
 
-    values.shuffle(&mut thread_rng());
+    match seed {
+        Some(seed) => values.shuffle(&mut StdRng::seed_from_u64(seed)),
+        None => values.shuffle(&mut thread_rng()),
+    }
 
     Ok((values.into_iter().map(ReturnSuccess::value)).to_output_stream())
 }
@@ -49,8 +49,14 @@ pub fn header_bold_from_value(bold_value: Option<&Value>) -> bool {
 pub fn table_mode(config: &NuConfig) -> TableTheme {
     let vars = &config.vars;
 
-    vars.get("table_mode")
-        .map_or(TableTheme::compact(), |mode| match mode.as_string() {
+    match vars.get("table_mode") {
+        // A record lets users describe their own glyphs instead of picking
+        // one of the named themes below, e.g. `{ corner: "+", horizontal:
+        // "-", vertical: "|", junction: "+", separate_header: true }`.
+        Some(mode) if mode.row_entries().next().is_some() => {
+            custom_table_theme(mode).unwrap_or_else(TableTheme::compact)
+        }
+        Some(mode) => match mode.as_string() {
             Ok(m) if m == "basic" => TableTheme::basic(),
             Ok(m) if m == "compact" => TableTheme::compact(),
             Ok(m) if m == "light" => TableTheme::light(),
@@ -62,7 +68,47 @@ pub fn table_mode(config: &NuConfig) -> TableTheme {
             Ok(m) if m == "heavy" => TableTheme::heavy(),
             Ok(m) if m == "none" => TableTheme::none(),
             _ => TableTheme::compact(),
-        })
+        },
+        None => TableTheme::compact(),
+    }
+}
+
+/// Builds a `TableTheme` from a user-supplied record instead of one of the
+/// built-in names above, so `table_mode` can name its own corner,
+/// horizontal, vertical, and junction glyphs (and whether a header
+/// separator is drawn) to match a terminal's own box-drawing preferences,
+/// without waiting on a new built-in theme. Fields left out of the record
+/// fall back to the ASCII glyphs `+` (corner), `-` (horizontal), `|`
+/// (vertical) and `+` (junction), and `separate_header` defaults to true.
+fn custom_table_theme(record: &Value) -> Option<TableTheme> {
+    let get_char = |key: &str, fallback: char| -> char {
+        row_field(record, key)
+            .and_then(|v| v.as_string().ok())
+            .and_then(|s| s.chars().next())
+            .unwrap_or(fallback)
+    };
+
+    let separate_header = row_field(record, "separate_header")
+        .map(|v| v.as_bool().unwrap_or(true))
+        .unwrap_or(true);
+
+    Some(TableTheme::custom(
+        get_char("corner", '+'),
+        get_char("horizontal", '-'),
+        get_char("vertical", '|'),
+        get_char("junction", '+'),
+        separate_header,
+    ))
+}
+
+fn row_field(record: &Value, key: &str) -> Option<Value> {
+    for (kee, value) in record.row_entries() {
+        if kee == key {
+            return Some(value.clone());
+        }
+    }
+
+    None
 }
 
 pub fn disabled_indexes(config: &NuConfig) -> bool {
@@ -0,0 +1,78 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{Dictionary, Primitive, ReturnSuccess, Signature, UntaggedValue, Value};
+use nu_source::{AnchorLocation, Tag};
+
+use indexmap::IndexMap;
+
+pub struct Tags;
+
+impl WholeStreamCommand for Tags {
+    fn name(&self) -> &str {
+        "tags"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("tags")
+    }
+
+    fn usage(&self) -> &str {
+        "Read the tags (metadata) for values."
+    }
+
+    fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        tags(args)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Get the tags of a piped value",
+            example: "echo [1 2 3] | tags",
+            result: None,
+        }]
+    }
+}
+
+fn tags(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let input = args.input;
+
+    Ok(input
+        .map(move |value| ReturnSuccess::value(tag_dict(&value)))
+        .to_output_stream())
+}
+
+fn tag_dict(value: &Value) -> Value {
+    let mut entries = IndexMap::new();
+
+    let mut span_entries = IndexMap::new();
+    span_entries.insert(
+        "start".to_string(),
+        UntaggedValue::int(value.tag.span.start() as i64).into_value(&value.tag),
+    );
+    span_entries.insert(
+        "end".to_string(),
+        UntaggedValue::int(value.tag.span.end() as i64).into_value(&value.tag),
+    );
+    entries.insert(
+        "span".to_string(),
+        UntaggedValue::Row(Dictionary::new(span_entries)).into_value(&value.tag),
+    );
+
+    entries.insert("anchor".to_string(), anchor_value(&value.tag));
+
+    UntaggedValue::Row(Dictionary::new(entries)).into_value(&value.tag)
+}
+
+/// Reports where a value's data actually came from, not just its `span`: the
+/// file path or URL it was read from, when the pipeline carries that far
+/// back. Only `open`/`from *`/`ls`/`fetch` (and similar source commands) tag
+/// their output with an anchor in the first place, so most values surface
+/// `$nothing` here.
+fn anchor_value(tag: &Tag) -> Value {
+    match &tag.anchor {
+        Some(AnchorLocation::File(path)) => UntaggedValue::string(path).into_value(tag),
+        Some(AnchorLocation::Url(url)) => UntaggedValue::string(url).into_value(tag),
+        _ => UntaggedValue::Primitive(Primitive::Nothing).into_value(tag),
+    }
+}
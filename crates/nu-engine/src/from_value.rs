@@ -0,0 +1,121 @@
+use nu_errors::ShellError;
+use nu_protocol::hir::CapturedBlock;
+use nu_protocol::{Primitive, ShellTypeName, UntaggedValue, Value};
+use nu_source::{Tagged, TaggedItem};
+use num_traits::cast::ToPrimitive;
+use std::convert::TryFrom;
+use std::path::PathBuf;
+
+/// Converts a single evaluated argument `Value` into a Rust type, with a
+/// spanned type-mismatch `ShellError` on failure. This is what `req`, `opt`,
+/// `rest`, `req_named` and `opt_named` build on, so command authors get
+/// per-argument coercion without constructing a whole serde struct through
+/// `ConfigDeserializer`.
+pub trait FromValue: Sized {
+    fn from_value(v: &Value) -> Result<Self, ShellError>;
+}
+
+fn type_mismatch(expected: &str, v: &Value) -> ShellError {
+    ShellError::labeled_error(
+        format!("Expected {}, found {}", expected, v.type_name()),
+        format!("needs to be {}", expected),
+        v.tag.span,
+    )
+}
+
+impl FromValue for Value {
+    fn from_value(v: &Value) -> Result<Self, ShellError> {
+        Ok(v.clone())
+    }
+}
+
+impl FromValue for String {
+    fn from_value(v: &Value) -> Result<Self, ShellError> {
+        match &v.value {
+            UntaggedValue::Primitive(Primitive::String(s)) => Ok(s.clone()),
+            UntaggedValue::Primitive(Primitive::Line(s)) => Ok(s.clone()),
+            _ => Err(type_mismatch("a string", v)),
+        }
+    }
+}
+
+impl FromValue for bool {
+    fn from_value(v: &Value) -> Result<Self, ShellError> {
+        match &v.value {
+            UntaggedValue::Primitive(Primitive::Boolean(b)) => Ok(*b),
+            _ => Err(type_mismatch("a boolean", v)),
+        }
+    }
+}
+
+impl FromValue for i64 {
+    fn from_value(v: &Value) -> Result<Self, ShellError> {
+        match &v.value {
+            UntaggedValue::Primitive(Primitive::Int(i)) => Ok(*i),
+            UntaggedValue::Primitive(Primitive::BigInt(i)) => {
+                i.to_i64().ok_or_else(|| type_mismatch("an integer", v))
+            }
+            _ => Err(type_mismatch("an integer", v)),
+        }
+    }
+}
+
+impl FromValue for u64 {
+    fn from_value(v: &Value) -> Result<Self, ShellError> {
+        let i = i64::from_value(v)?;
+        u64::try_from(i).map_err(|_| type_mismatch("a non-negative integer", v))
+    }
+}
+
+impl FromValue for usize {
+    fn from_value(v: &Value) -> Result<Self, ShellError> {
+        let i = i64::from_value(v)?;
+        usize::try_from(i).map_err(|_| type_mismatch("a non-negative integer", v))
+    }
+}
+
+impl FromValue for f64 {
+    fn from_value(v: &Value) -> Result<Self, ShellError> {
+        match &v.value {
+            UntaggedValue::Primitive(Primitive::Decimal(d)) => {
+                d.to_f64().ok_or_else(|| type_mismatch("a number", v))
+            }
+            UntaggedValue::Primitive(Primitive::Int(i)) => Ok(*i as f64),
+            _ => Err(type_mismatch("a number", v)),
+        }
+    }
+}
+
+impl FromValue for PathBuf {
+    fn from_value(v: &Value) -> Result<Self, ShellError> {
+        match &v.value {
+            UntaggedValue::Primitive(Primitive::FilePath(p)) => Ok(p.clone()),
+            UntaggedValue::Primitive(Primitive::String(s)) => Ok(PathBuf::from(s)),
+            _ => Err(type_mismatch("a file path", v)),
+        }
+    }
+}
+
+impl FromValue for CapturedBlock {
+    fn from_value(v: &Value) -> Result<Self, ShellError> {
+        match &v.value {
+            UntaggedValue::Block(captured) => Ok((**captured).clone()),
+            _ => Err(type_mismatch("a block", v)),
+        }
+    }
+}
+
+impl<T: FromValue> FromValue for Tagged<T> {
+    fn from_value(v: &Value) -> Result<Self, ShellError> {
+        Ok(T::from_value(v)?.tagged(v.tag.clone()))
+    }
+}
+
+impl<T: FromValue> FromValue for Vec<T> {
+    fn from_value(v: &Value) -> Result<Self, ShellError> {
+        match &v.value {
+            UntaggedValue::Table(rows) => rows.iter().map(T::from_value).collect(),
+            _ => Ok(vec![T::from_value(v)?]),
+        }
+    }
+}
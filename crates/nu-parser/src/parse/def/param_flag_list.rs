@@ -3,9 +3,9 @@
 /// [ (parameter | flag | rest_param | <eol>)* ]
 ///Where
 ///parameter is:
-///    name (<:> type)? (<?>)? item_end
+///    name (<?>)? (<:> type)? (<=> default)? item_end
 ///flag is:
-///    --name (-shortform)? (<:> type)? item_end
+///    --name (-shortform)? (required)? (<:> type (<...>)?)? (<=> default)? item_end
 ///rest is:
 ///    ...rest (<:> type)? item_end
 ///item_end:
@@ -16,6 +16,7 @@ use log::debug;
 use crate::{
     lex::{lex, Token, TokenContents},
     parse::def::lib_code::parse_lib::{And2, CheckedParse, IfSuccessThen, Maybe, Parse},
+    parse::util::token_to_spanned_string,
 };
 use nu_errors::ParseError;
 use nu_protocol::{NamedType, PositionalType, Signature, SyntaxShape};
@@ -23,7 +24,7 @@ use nu_source::{Span, Spanned};
 
 use super::{
     lib_code::{
-        parse_lib::{And3, ParseInto, WithSpan},
+        parse_lib::{And3, And4, And5, ParseInto, WithSpan},
         ParseResult,
     },
     primitives::{
@@ -32,23 +33,32 @@ use super::{
     },
 };
 
+///Parses the parameter/flag list signature of a `def`.
+///
+///Unlike a typical combinator parser that stops at the first error, this
+///plows through a malformed item list: a failed `Parameter`/`Flag`/`Rest`
+///parse is recorded and the cursor is resynchronized to the next
+///`ItemEnd` boundary (comma or eol) before the loop resumes, instead of
+///resuming from whatever partial position the failed sub-parser left
+///behind. That keeps one bad type name from cascading into spurious
+///errors on every parameter after it, so all the real mistakes in a long
+///`def [...]` signature are reported together in one pass (e.g. for a
+///caller to render all at once via codespan-reporting).
 pub(crate) fn parse_signature(
     name: &str,
     signature_vec: &Spanned<String>,
-) -> (Signature, Option<ParseError>) {
-    let mut err = None;
+) -> (Signature, Vec<ParseError>) {
+    let mut errors = vec![];
 
     let mut chars = signature_vec.chars();
 
     match (chars.next(), chars.next_back()) {
         (Some('['), Some(']')) => {}
         _ => {
-            err = err.or_else(|| {
-                Some(ParseError::mismatch(
-                    "definition signature",
-                    signature_vec.clone(),
-                ))
-            });
+            errors.push(ParseError::mismatch(
+                "definition signature",
+                signature_vec.clone(),
+            ));
         }
     }
 
@@ -59,12 +69,18 @@ pub(crate) fn parse_signature(
         signature_vec.span.start() + 1
     );
     let (tokens, error) = lex(&string, signature_vec.span.start() + 1);
-    err = err.or(error);
+    errors.extend(error);
 
     //After normal lexing, tokens also need to be split on ',' and ':'
+    //'<' and '>' are split off too so a type annotation like `list<string>`
+    //can be recognized as a generic shape rather than one opaque token.
+    //'=' is split off so a trailing literal default (`threshold: int = 10`)
+    //is lexed as its own terminal instead of being glued onto the type name.
     //TODO this could probably be all done in a specialized lexing function
-    let tokens = lex_split_baseline_tokens_on(tokens, &[',', ':', '?']);
+    let tokens = lex_split_baseline_tokens_on(tokens, &[',', ':', '?', '<', '>', '=']);
     let tokens = lex_split_shortflag_from_longflag(tokens);
+    let tokens = lex_split_trailing_ellipsis(tokens);
+    let tokens = lex_split_keyword_parens(tokens);
     debug!("Tokens are {:?}", tokens);
 
     let mut parameters = vec![];
@@ -77,60 +93,105 @@ pub(crate) fn parse_signature(
             //Skip leading eol
             i += 1;
         } else if is_flag(&tokens[i]) {
+            let start = i;
             let ParseResult {
                 value: flag,
                 i: i_new,
                 err: error,
             } = Flag::parse_debug(&tokens, i);
-            err = err.or(error);
-            i = i_new;
             flags.push(flag);
+            i = match error {
+                Some(error) => {
+                    errors.push(error);
+                    resync_to_item_end(&tokens, start)
+                }
+                None => i_new,
+            };
         } else if is_rest(&tokens[i]) {
+            let start = i;
             let ParseResult {
                 value: rest_,
                 i: i_new,
                 err: error,
             } = Rest::parse_debug(&tokens, i);
-            err = err.or(error);
-            i = i_new;
             rest = Some(rest_);
+            i = match error {
+                Some(error) => {
+                    errors.push(error);
+                    resync_to_item_end(&tokens, start)
+                }
+                None => i_new,
+            };
         } else {
+            let start = i;
             let ParseResult {
                 value: parameter,
                 i: i_new,
                 err: error,
             } = Parameter::parse_debug(&tokens, i);
-            err = err.or(error);
-            i = i_new;
             parameters.push(parameter);
+            i = match error {
+                Some(error) => {
+                    errors.push(error);
+                    resync_to_item_end(&tokens, start)
+                }
+                None => i_new,
+            };
         }
     }
 
     let signature = to_signature(name, parameters, flags, rest);
     debug!("Signature: {:?}", signature);
 
-    (signature, err)
+    (signature, errors)
+}
+
+///Skips forward from `i` to just past the next item-end boundary (a `,`
+///baseline token or an eol), or to the end of `tokens` if none remain.
+///Used to recover from a malformed parameter/flag/rest item without
+///letting its corrupted state confuse the parse of the next item.
+fn resync_to_item_end(tokens: &[Token], i: usize) -> usize {
+    let mut i = i;
+    while i < tokens.len() {
+        if is_baseline(tokens.get(i), ",") || tokens[i].contents.is_eol() {
+            return i + 1;
+        }
+        i += 1;
+    }
+    i
 }
 
 impl CheckedParse for Parameter {}
 impl
     From<(
-        Spanned<(String, Option<()>, Option<SyntaxShape>)>,
+        Spanned<(
+            String,
+            Option<()>,
+            Option<SyntaxShape>,
+            Option<Spanned<String>>,
+        )>,
         Option<String>,
     )> for Parameter
 {
     fn from(
         (spanned_param, comment): (
-            Spanned<(String, Option<()>, Option<SyntaxShape>)>,
+            Spanned<(
+                String,
+                Option<()>,
+                Option<SyntaxShape>,
+                Option<Spanned<String>>,
+            )>,
             Option<String>,
         ),
     ) -> Self {
         let span = spanned_param.span;
-        let (name, optional, type_) = spanned_param.item;
+        let (name, optional, type_, default) = spanned_param.item;
         let type_ = type_.unwrap_or(SyntaxShape::Any);
 
-        let pos_type = if optional.is_some() {
-            PositionalType::optional(&name, type_)
+        //A parameter carrying a default is implicitly optional, even without
+        //a `?` modifier.
+        let pos_type = if optional.is_some() || default.is_some() {
+            PositionalType::optional(&name, type_, default.map(|d| d.item))
         } else {
             PositionalType::mandatory(&name, type_)
         };
@@ -145,7 +206,10 @@ impl Parse for Parameter {
     fn parse(tokens: &[Token], i: usize) -> ParseResult<Self::Output> {
         let result = ParseInto::<
             Parameter,
-            And2<WithSpan<And3<ParameterName, Maybe<OptionalModifier>, OptionalType>>, ItemEnd>,
+            And2<
+                WithSpan<And4<ParameterName, Maybe<OptionalModifier>, OptionalType, DefaultValue>>,
+                ItemEnd,
+            >,
         >::parse(tokens, i);
 
         debug!(
@@ -166,47 +230,72 @@ impl Parse for Parameter {
     }
 }
 
-impl
-    From<(
-        Spanned<(String, Option<char>, Option<SyntaxShape>)>,
-        Option<String>,
-    )> for Flag
-{
-    fn from(
-        (spanned_flag, comment): (
-            Spanned<(String, Option<char>, Option<SyntaxShape>)>,
-            Option<String>,
-        ),
-    ) -> Self {
+impl CheckedParse for Flag {}
+impl Parse for Flag {
+    type Output = Flag;
+
+    fn parse(tokens: &[Token], i: usize) -> ParseResult<Self::Output> {
+        let ParseResult {
+            value: (spanned_flag, comment),
+            i,
+            err,
+        } = And2::<
+            WithSpan<
+                And5<
+                    FlagName,
+                    Maybe<FlagShortName>,
+                    Maybe<RequiredModifier>,
+                    FlagType,
+                    DefaultValue,
+                >,
+            >,
+            ItemEnd,
+        >::parse(tokens, i);
+
         let span = spanned_flag.span;
-        let (name, shortform, type_) = spanned_flag.item;
+        let (name, shortform, required, (type_, repeated), default) = spanned_flag.item;
+        let required = required.is_some();
+
+        //A flag marked `(required)` must always be supplied by the caller,
+        //so it can never also carry a default to fall back on.
+        let conflict_err = if required && default.is_some() {
+            default
+                .clone()
+                .map(|d| ParseError::mismatch("a required flag without a default", d))
+        } else {
+            None
+        };
 
-        //If no type is given, the flag is a switch. Otherwise its optional
+        //If no type is given, the flag is a switch. Otherwise it's a named
+        //argument, mandatory or optional depending on `(required)`, and
+        //repeatable (collecting every occurrence into a list) if the type
+        //carries a `...` suffix.
         //Example:
         //--verbose(-v) # Switch
         //--output(-o): path # Optional flag
-        let named_type = if let Some(shape) = type_ {
-            NamedType::Optional(shortform, shape)
-        } else {
-            NamedType::Switch(shortform)
+        //--name: string = "world" # Optional flag with a default
+        //--name (required): string # Mandatory flag
+        //--tag: string... # Repeatable flag, collected into a list
+        let named_type = match type_ {
+            Some(shape) => {
+                let shape = if repeated {
+                    SyntaxShape::List(Box::new(shape))
+                } else {
+                    shape
+                };
+                if required {
+                    NamedType::Mandatory(shortform, shape)
+                } else {
+                    NamedType::Optional(shortform, shape, default.map(|d| d.item))
+                }
+            }
+            None => NamedType::Switch(shortform),
         };
 
-        Flag::new(name, named_type, comment, span)
-    }
-}
-
-impl CheckedParse for Flag {}
-impl Parse for Flag {
-    type Output = Flag;
-
-    fn parse(tokens: &[Token], i: usize) -> ParseResult<Self::Output> {
-        let result = ParseInto::<
-            Flag,
-            And2<WithSpan<And3<FlagName, Maybe<FlagShortName>, OptionalType>>, ItemEnd>,
-        >::parse(tokens, i);
+        let flag = Flag::new(name, named_type, comment, span);
+        debug!("Parsed flag: {:?}", flag);
 
-        debug!("Parsed flag: {:?}", result.value);
-        result
+        ParseResult::new(flag, i, err.or(conflict_err))
     }
 
     fn display_name() -> String {
@@ -352,6 +441,84 @@ pub(crate) fn lex_split_shortflag_from_longflag(tokens: Vec<Token>) -> Vec<Token
     }
     result
 }
+//A type's trailing repeat marker (`string...`) is lexed glued onto the
+//type name, since the lexer has no notion of it. Split a baseline token's
+//trailing "..." off into its own token so `FlagType` can recognize it
+//like any other suffix token, without disturbing a leading "..." (the
+//rest-param marker `...rest`, handled separately by `is_rest`).
+pub(crate) fn lex_split_trailing_ellipsis(tokens: Vec<Token>) -> Vec<Token> {
+    let mut result = Vec::with_capacity(tokens.len());
+    for token in tokens {
+        if let TokenContents::Baseline(base) = &token.contents {
+            if base.len() > 3 && base.ends_with("...") && !base.starts_with("...") {
+                let split_at = base.len() - 3;
+                let split_span_i = token.span.start() + split_at;
+                result.push(Token::new(
+                    TokenContents::Baseline(base[..split_at].to_string()),
+                    Span::new(token.span.start(), split_span_i),
+                ));
+                result.push(Token::new(
+                    TokenContents::Baseline("...".to_string()),
+                    Span::new(split_span_i, token.span.end()),
+                ));
+                continue;
+            }
+        }
+        result.push(token);
+    }
+    result
+}
+
+//`keyword("word", shape)`'s outer parens need to stand on their own so
+//`parse_keyword_shape` can step through them like any other token, but
+//they must stay out of the way of the balanced `(-f)`/`(required)` forms
+//that `lex_split_shortflag_from_longflag`/`RequiredModifier` rely on
+//staying glued. Those are balanced within one token (opens and closes
+//without crossing a whitespace boundary); `keyword(`'s open and its
+//matching `)` never are, once the comma/shortflag splitters above have
+//already run, so splitting only the unbalanced case is unambiguous.
+pub(crate) fn lex_split_keyword_parens(tokens: Vec<Token>) -> Vec<Token> {
+    let mut result = Vec::with_capacity(tokens.len());
+    for token in tokens {
+        if let TokenContents::Baseline(base) = &token.contents {
+            let starts_unbalanced_open = base.starts_with('(') && !base.ends_with(')');
+            let ends_unbalanced_close =
+                base.len() > 1 && base.ends_with(')') && !base.starts_with('(');
+
+            if starts_unbalanced_open {
+                let offset = token.span.start();
+                result.push(Token::new(
+                    TokenContents::Baseline("(".to_string()),
+                    Span::new(offset, offset + 1),
+                ));
+                let rest = &base[1..];
+                if !rest.is_empty() {
+                    result.push(Token::new(
+                        TokenContents::Baseline(rest.to_string()),
+                        Span::new(offset + 1, token.span.end()),
+                    ));
+                }
+                continue;
+            }
+            if ends_unbalanced_close {
+                let offset = token.span.start();
+                let rest = &base[..base.len() - 1];
+                result.push(Token::new(
+                    TokenContents::Baseline(rest.to_string()),
+                    Span::new(offset, token.span.end() - 1),
+                ));
+                result.push(Token::new(
+                    TokenContents::Baseline(")".to_string()),
+                    Span::new(token.span.end() - 1, token.span.end()),
+                ));
+                continue;
+            }
+        }
+        result.push(token);
+    }
+    result
+}
+
 //Currently the lexer does not split baselines on ',' ':' '?'
 //The parameter list requires this. Therefore here is a hacky method doing this.
 pub(crate) fn lex_split_baseline_tokens_on(
@@ -423,7 +590,7 @@ impl Parameter {
 
     pub fn error() -> Parameter {
         Parameter::new(
-            PositionalType::optional("Internal Error", SyntaxShape::Any),
+            PositionalType::optional("Internal Error", SyntaxShape::Any, None),
             Some(
                 "Wanted to parse a parameter, but no input present. Please report this error!"
                     .to_string(),
@@ -476,13 +643,30 @@ impl Parse for OptionalType {
     type Output = Option<SyntaxShape>;
 
     fn parse(tokens: &[Token], i: usize) -> ParseResult<Self::Output> {
+        //`keyword(...)` is a pseudo-type, not one `Shape` itself knows how
+        //to resolve, so it's recognized here before falling through to the
+        //normal `: <shape>` path.
+        if is_baseline(tokens.get(i), ":") && is_baseline(tokens.get(i + 1), "keyword") {
+            let ParseResult {
+                value: shape,
+                i: i_new,
+                err,
+            } = parse_keyword_shape(tokens, i + 1);
+            return ParseResult::new(Some(shape), i_new, err);
+        }
+
         let ParseResult {
             value,
             i: i_new,
             err,
         } = IfSuccessThen::<DoublePoint, Shape>::parse(tokens, i);
         if let Some((_, shape)) = value {
-            ParseResult::new(Some(shape), i_new, err)
+            let ParseResult {
+                value: shape,
+                i: i_new,
+                err: generic_err,
+            } = parse_generic_suffix(tokens, i_new, shape);
+            ParseResult::new(Some(shape), i_new, err.or(generic_err))
         } else {
             ParseResult::new(None, i, None)
         }
@@ -496,3 +680,275 @@ impl Parse for OptionalType {
         Some(SyntaxShape::Any)
     }
 }
+
+///Parses an optional `= <literal>` suffix trailing a parameter or flag's
+///type, e.g. the `= 10` in `threshold: int = 10`. Carries the raw literal
+///token through as a `Spanned<String>` rather than resolving it against the
+///declared `SyntaxShape` here; coercing the text into a typed `Value` for
+///the slot happens where the rest of the default is consumed, once the
+///caller is known to have omitted the argument.
+struct DefaultValue {}
+impl CheckedParse for DefaultValue {}
+
+impl Parse for DefaultValue {
+    type Output = Option<Spanned<String>>;
+
+    fn parse(tokens: &[Token], i: usize) -> ParseResult<Self::Output> {
+        if !is_baseline(tokens.get(i), "=") {
+            return ParseResult::new(None, i, None);
+        }
+        let eq_span = tokens[i].span;
+        let i = i + 1;
+
+        match tokens.get(i) {
+            Some(token) => ParseResult::new(Some(token_to_spanned_string(token)), i + 1, None),
+            None => ParseResult::new(
+                None,
+                i,
+                Some(ParseError::mismatch(
+                    "a default value after =",
+                    token_or_eof_spanned_string(tokens, i, eq_span),
+                )),
+            ),
+        }
+    }
+
+    fn display_name() -> String {
+        "default value".to_string()
+    }
+
+    fn default_error_value() -> Self::Output {
+        None
+    }
+}
+
+///Matches a flag's `(required)` modifier, marking it as a named argument
+///the caller must always supply rather than an optional one.
+struct RequiredModifier {}
+impl CheckedParse for RequiredModifier {}
+
+impl Parse for RequiredModifier {
+    type Output = ();
+
+    fn parse(tokens: &[Token], i: usize) -> ParseResult<Self::Output> {
+        if is_baseline(tokens.get(i), "(required)") {
+            ParseResult::new((), i + 1, None)
+        } else {
+            let span = tokens.get(i).map(|t| t.span).unwrap_or_else(Span::unknown);
+            ParseResult::new(
+                (),
+                i,
+                Some(ParseError::mismatch(
+                    Self::display_name(),
+                    token_or_eof_spanned_string(tokens, i, span),
+                )),
+            )
+        }
+    }
+
+    fn display_name() -> String {
+        "(required)".to_string()
+    }
+
+    fn default_error_value() -> Self::Output {}
+}
+
+///Parses a flag's `(: type)?` annotation together with an optional
+///trailing `...` suffix that marks the flag as repeatable, e.g. the
+///`string...` in `--tag: string...`. A repeatable flag collects every
+///occurrence the caller passes into a list instead of keeping only the
+///last one.
+struct FlagType {}
+impl CheckedParse for FlagType {}
+
+impl Parse for FlagType {
+    type Output = (Option<SyntaxShape>, bool);
+
+    fn parse(tokens: &[Token], i: usize) -> ParseResult<Self::Output> {
+        let ParseResult {
+            value: shape,
+            i,
+            err,
+        } = OptionalType::parse_debug(tokens, i);
+
+        if shape.is_some() && is_baseline(tokens.get(i), "...") {
+            ParseResult::new((shape, true), i + 1, err)
+        } else {
+            ParseResult::new((shape, false), i, err)
+        }
+    }
+
+    fn display_name() -> String {
+        OptionalType::display_name()
+    }
+
+    fn default_error_value() -> Self::Output {
+        (OptionalType::default_error_value(), false)
+    }
+}
+
+///Detects a `<` ... `>` suffix right after a just-parsed shape (e.g. the
+///`<string>` in `list<string>`) and recursively resolves it into a nested
+///`SyntaxShape::List`, so container element types survive `def` signature
+///parsing instead of degrading to `SyntaxShape::Any`. Nesting
+///(`list<list<int>>`) works because the inner shape is resolved through this
+///same function before the outer `List` is built.
+fn parse_generic_suffix(
+    tokens: &[Token],
+    i: usize,
+    base_shape: SyntaxShape,
+) -> ParseResult<SyntaxShape> {
+    if !is_baseline(tokens.get(i), "<") {
+        return ParseResult::new(base_shape, i, None);
+    }
+    let open_span = tokens[i].span;
+    let i = i + 1;
+
+    if is_baseline(tokens.get(i), ">") {
+        return ParseResult::new(
+            base_shape,
+            i + 1,
+            Some(ParseError::mismatch(
+                "a type argument inside <>",
+                token_or_eof_spanned_string(tokens, i, open_span),
+            )),
+        );
+    }
+    if i >= tokens.len() {
+        return ParseResult::new(
+            base_shape,
+            i,
+            Some(ParseError::mismatch(
+                "closing > for type argument",
+                token_or_eof_spanned_string(tokens, i, open_span),
+            )),
+        );
+    }
+
+    let ParseResult {
+        value: inner_shape,
+        i: i_new,
+        err,
+    } = Shape::parse_debug(tokens, i);
+
+    let ParseResult {
+        value: inner_shape,
+        i: i_new,
+        err: inner_err,
+    } = parse_generic_suffix(tokens, i_new, inner_shape);
+    let err = err.or(inner_err);
+
+    if is_baseline(tokens.get(i_new), ">") {
+        ParseResult::new(SyntaxShape::List(Box::new(inner_shape)), i_new + 1, err)
+    } else {
+        let bad_span = token_or_eof_spanned_string(tokens, i_new, open_span);
+        ParseResult::new(
+            SyntaxShape::List(Box::new(inner_shape)),
+            i_new,
+            err.or_else(|| {
+                Some(ParseError::mismatch(
+                    "closing > for type argument",
+                    bad_span,
+                ))
+            }),
+        )
+    }
+}
+
+///Parses the `keyword("word", shape)` pseudo-type used to require a
+///literal token ahead of a positional argument, e.g. `keyword("csv", path)`
+///forces the caller to write the bare word `csv` before a `path`
+///argument. `i` must point at the `keyword` token itself. Resolves into
+///`SyntaxShape::Keyword`, so a `def`-defined command can dispatch on a
+///literal subcommand word without matching it as a string in its body.
+fn parse_keyword_shape(tokens: &[Token], i: usize) -> ParseResult<SyntaxShape> {
+    let open_span = tokens[i].span;
+    let i = i + 1; // consume "keyword"
+
+    if !is_baseline(tokens.get(i), "(") {
+        return ParseResult::new(
+            SyntaxShape::Any,
+            i,
+            Some(ParseError::mismatch(
+                "( after keyword",
+                token_or_eof_spanned_string(tokens, i, open_span),
+            )),
+        );
+    }
+    let i = i + 1;
+
+    let word = match tokens.get(i) {
+        Some(token) => unquote(&token_to_spanned_string(token).item),
+        None => {
+            return ParseResult::new(
+                SyntaxShape::Any,
+                i,
+                Some(ParseError::mismatch(
+                    "a literal word",
+                    token_or_eof_spanned_string(tokens, i, open_span),
+                )),
+            )
+        }
+    };
+    let i = i + 1;
+
+    if !is_baseline(tokens.get(i), ",") {
+        return ParseResult::new(
+            SyntaxShape::Keyword(word, Box::new(SyntaxShape::Any)),
+            i,
+            Some(ParseError::mismatch(
+                ", after keyword's literal word",
+                token_or_eof_spanned_string(tokens, i, open_span),
+            )),
+        );
+    }
+    let i = i + 1;
+
+    let ParseResult {
+        value: inner_shape,
+        i,
+        err,
+    } = Shape::parse_debug(tokens, i);
+
+    if !is_baseline(tokens.get(i), ")") {
+        return ParseResult::new(
+            SyntaxShape::Keyword(word, Box::new(inner_shape)),
+            i,
+            err.or_else(|| {
+                Some(ParseError::mismatch(
+                    "closing ) for keyword",
+                    token_or_eof_spanned_string(tokens, i, open_span),
+                ))
+            }),
+        );
+    }
+
+    ParseResult::new(
+        SyntaxShape::Keyword(word, Box::new(inner_shape)),
+        i + 1,
+        err,
+    )
+}
+
+///Strips one layer of surrounding double quotes off a keyword's literal
+///word (`"csv"` -> `csv`), leaving an already-bare word untouched.
+fn unquote(text: &str) -> String {
+    text.strip_prefix('"')
+        .and_then(|t| t.strip_suffix('"'))
+        .unwrap_or(text)
+        .to_string()
+}
+
+fn is_baseline(token: Option<&Token>, text: &str) -> bool {
+    matches!(token.map(|t| &t.contents), Some(TokenContents::Baseline(base)) if base == text)
+}
+
+fn token_or_eof_spanned_string(tokens: &[Token], i: usize, fallback_span: Span) -> Spanned<String> {
+    match tokens.get(i) {
+        Some(token) => token_to_spanned_string(token),
+        None => Spanned {
+            item: "".to_string(),
+            span: fallback_span,
+        },
+    }
+}
@@ -0,0 +1,50 @@
+use nu_errors::ShellError;
+use nu_plugin::{serve_plugin, Plugin};
+use nu_protocol::{CallInfo, ReturnSuccess, ReturnValue, Signature, Value};
+use nu_source::Tag;
+
+mod ps;
+use ps::ps;
+
+struct Ps;
+
+impl Ps {
+    fn new() -> Ps {
+        Ps
+    }
+}
+
+impl Plugin for Ps {
+    fn config(&mut self) -> Result<Signature, ShellError> {
+        Ok(Signature::build("ps")
+            .desc("View information about system processes.")
+            .switch(
+                "long",
+                "list all available columns for each entry",
+                Some('l'),
+            )
+            .switch("tree", "show processes as a parent/child tree", Some('t'))
+            .filter())
+    }
+
+    fn begin_filter(&mut self, callinfo: CallInfo) -> Result<Vec<ReturnValue>, ShellError> {
+        let long = callinfo.args.has_flag("long");
+        let tree = callinfo.args.has_flag("tree");
+        let tag = Tag::unknown();
+
+        let mut runtime = tokio::runtime::Runtime::new()
+            .map_err(|_| ShellError::untagged_runtime_error("Could not create tokio runtime"))?;
+
+        runtime
+            .block_on(ps(tag, long, tree))
+            .map(|list| list.into_iter().map(ReturnSuccess::value).collect())
+    }
+
+    fn filter(&mut self, _: Value) -> Result<Vec<ReturnValue>, ShellError> {
+        Ok(vec![])
+    }
+}
+
+fn main() {
+    serve_plugin(&mut Ps::new());
+}
@@ -1,7 +1,9 @@
+use std::collections::{HashMap, HashSet};
+
 use nu_errors::ShellError;
 use nu_protocol::{TaggedDictBuilder, UntaggedValue, Value};
 use nu_source::Tag;
-use sysinfo::{ProcessExt, System, SystemExt};
+use sysinfo::{Pid, ProcessExt, System, SystemExt};
 
 #[derive(Default)]
 pub struct Ps;
@@ -22,17 +24,18 @@ impl Ps {
 //     Ok((process, usage_2 - usage_1, memory))
 // }
 
-pub async fn ps(tag: Tag, long: bool) -> Result<Vec<Value>, ShellError> {
+pub async fn ps(tag: Tag, long: bool, tree: bool) -> Result<Vec<Value>, ShellError> {
     let mut sys = System::new_all();
     sys.refresh_all();
     let duration = std::time::Duration::from_millis(500);
     std::thread::sleep(duration);
     sys.refresh_all();
 
-    let mut output = vec![];
-
     let result = sys.get_processes();
 
+    let mut rows = HashMap::new();
+    let mut parents = HashMap::new();
+
     for (pid, process) in result.iter() {
         let mut dict = TaggedDictBuilder::new(&tag);
         dict.insert_untagged("pid", UntaggedValue::int(*pid));
@@ -56,9 +59,18 @@ pub async fn ps(tag: Tag, long: bool) -> Result<Vec<Value>, ShellError> {
             dict.insert_untagged("command", UntaggedValue::string(process.cmd().join(" ")));
         }
 
-        output.push(dict.into_value());
+        if let Some(parent) = process.parent() {
+            parents.insert(*pid, parent);
+        }
+        rows.insert(*pid, dict.into_value());
+    }
+
+    if tree {
+        return Ok(build_forest(&rows, &parents, &tag));
     }
 
+    let output = rows.into_iter().map(|(_, row)| row).collect();
+
     // let processes = process::processes()
     //     .await
     //     .map_err(|_| {
@@ -133,3 +145,67 @@ pub async fn ps(tag: Tag, long: bool) -> Result<Vec<Value>, ShellError> {
     // }
     Ok(output)
 }
+
+///Builds the parent/child forest from the flat `pid -> parent pid` map,
+///giving each process row a `children` column instead of leaving the
+///table flat. A process whose parent is missing or wasn't part of this
+///snapshot becomes a root.
+fn build_forest(rows: &HashMap<Pid, Value>, parents: &HashMap<Pid, Pid>, tag: &Tag) -> Vec<Value> {
+    let mut children: HashMap<Pid, Vec<Pid>> = HashMap::new();
+    for (pid, parent) in parents {
+        if rows.contains_key(parent) {
+            children.entry(*parent).or_insert_with(Vec::new).push(*pid);
+        }
+    }
+
+    rows.keys()
+        .filter(|pid| {
+            parents
+                .get(pid)
+                .map(|parent| !rows.contains_key(parent))
+                .unwrap_or(true)
+        })
+        .filter_map(|&pid| build_node(pid, rows, &children, tag, &mut HashSet::new()))
+        .collect()
+}
+
+///Recursively attaches each of `pid`'s children (as their own nested
+///`children` tables) to its row. `visited` guards against a malformed
+///parent pointer creating a cycle: once a pid has been visited on the
+///current path, it is not descended into again.
+fn build_node(
+    pid: Pid,
+    rows: &HashMap<Pid, Value>,
+    children: &HashMap<Pid, Vec<Pid>>,
+    tag: &Tag,
+    visited: &mut HashSet<Pid>,
+) -> Option<Value> {
+    if !visited.insert(pid) {
+        return None;
+    }
+
+    let row = rows.get(&pid)?.clone();
+    let child_rows: Vec<Value> = children
+        .get(&pid)
+        .into_iter()
+        .flatten()
+        .filter_map(|&child_pid| build_node(child_pid, rows, children, tag, visited))
+        .collect();
+
+    Some(with_children(row, child_rows, tag))
+}
+
+///Adds a `children` column holding `child_rows` to an already-built
+///process row.
+fn with_children(row: Value, child_rows: Vec<Value>, tag: &Tag) -> Value {
+    match row.value {
+        UntaggedValue::Row(mut dict) => {
+            dict.entries.insert(
+                "children".to_string(),
+                UntaggedValue::table(&child_rows).into_value(tag),
+            );
+            UntaggedValue::Row(dict).into_value(tag)
+        }
+        other => other.into_value(tag),
+    }
+}
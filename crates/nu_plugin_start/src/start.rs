@@ -81,11 +81,63 @@ impl Start {
             .unwrap();
     }
     #[cfg(target_os = "windows")]
-    pub fn exec(&mut self) {}
+    pub fn exec(&mut self) {
+        let application = self.application.clone();
+
+        for filename in self.filenames.drain(..) {
+            let result = if let Some(app_name) = &application {
+                Command::new(app_name)
+                    .stdout(Stdio::null())
+                    .stderr(Stdio::null())
+                    .arg(&filename)
+                    .spawn()
+            } else {
+                Command::new("cmd")
+                    .stdout(Stdio::null())
+                    .stderr(Stdio::null())
+                    .args(&["/C", "start", "", &filename])
+                    .spawn()
+            };
+
+            if let Err(e) = result {
+                print_warning(format!("Could not open '{}': {}", filename, e));
+            }
+        }
+    }
 
     #[cfg(not(any(target_os = "windows", target_os = "macos")))]
     pub fn exec(&mut self) {
-        // executing on linux
+        let application = self.application.clone();
+
+        for filename in self.filenames.drain(..) {
+            let is_url = !Path::new(&filename).exists() && url::Url::parse(&filename).is_ok();
+
+            let result = if let Some(app_name) = &application {
+                Command::new(app_name)
+                    .stdout(Stdio::null())
+                    .stderr(Stdio::null())
+                    .arg(&filename)
+                    .spawn()
+            } else if is_url {
+                let browser = std::env::var("BROWSER").unwrap_or_else(|_| "xdg-open".to_string());
+
+                Command::new(browser)
+                    .stdout(Stdio::null())
+                    .stderr(Stdio::null())
+                    .arg(&filename)
+                    .spawn()
+            } else {
+                Command::new("xdg-open")
+                    .stdout(Stdio::null())
+                    .stderr(Stdio::null())
+                    .arg(&filename)
+                    .spawn()
+            };
+
+            if let Err(e) = result {
+                print_warning(format!("Could not open '{}': {}", filename, e));
+            }
+        }
     }
 }
 
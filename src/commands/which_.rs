@@ -35,7 +35,7 @@ impl WholeStreamCommand for Which {
 }
 
 /// Shortcuts for creating an entry to the output table
-fn entry(arg: impl Into<String>, path: Value, builtin: bool, tag: Tag) -> Value {
+fn entry(arg: impl Into<String>, path: Value, kind: &str, tag: Tag) -> Value {
     let mut map = IndexMap::new();
     map.insert(
         "arg".to_string(),
@@ -43,20 +43,20 @@ fn entry(arg: impl Into<String>, path: Value, builtin: bool, tag: Tag) -> Value
     );
     map.insert("path".to_string(), path);
     map.insert(
-        "builtin".to_string(),
-        UntaggedValue::Primitive(Primitive::Boolean(builtin)).into_value(tag.clone()),
+        "type".to_string(),
+        UntaggedValue::Primitive(Primitive::String(kind.to_string())).into_value(tag.clone()),
     );
 
     UntaggedValue::row(map).into_value(tag.clone())
 }
 
-macro_rules! entry_builtin {
-    ($arg:expr, $tag:expr) => {
+macro_rules! entry_kind {
+    ($arg:expr, $kind:expr, $tag:expr) => {
         entry(
             $arg,
-            UntaggedValue::Primitive(Primitive::String("nushell built-in command".to_string()))
+            UntaggedValue::Primitive(Primitive::String(format!("nushell {} command", $kind)))
                 .into_value($tag.clone()),
-            true,
+            $kind,
             $tag,
         )
     };
@@ -67,7 +67,7 @@ macro_rules! entry_path {
         entry(
             $arg,
             UntaggedValue::Primitive(Primitive::Path($path)).into_value($tag.clone()),
-            false,
+            "external",
             $tag,
         )
     };
@@ -80,15 +80,37 @@ struct WhichArgs {
 }
 
 fn which(
-    WhichArgs { bin, .. }: WhichArgs,
+    WhichArgs { bin, all }: WhichArgs,
     RunnableContext { commands, .. }: RunnableContext,
 ) -> Result<OutputStream, ShellError> {
     let stream = async_stream! {
-        if commands.has(&bin.item) {
-            yield ReturnSuccess::value(entry_builtin!(&bin.item, bin.tag.clone()))
-        } else if let Ok(ok) = ichwh::which(&bin.item).await {
-            yield ReturnSuccess::value(entry_path!(&bin.item, ok.into(), bin.tag.clone()))
-        } else {
+        // Aliases and custom (`def`) commands shadow externals. A matching
+        // alias and command are always reported; `--all` lists every matching
+        // external on the PATH instead of just the first one found.
+        if let Some(_) = commands.get_alias(&bin.item) {
+            yield ReturnSuccess::value(entry_kind!(&bin.item, "alias", bin.tag.clone()))
+        }
+
+        if let Some(command) = commands.get_command(&bin.item) {
+            let kind = if command.is_binary() { "built-in" } else { "custom" };
+            yield ReturnSuccess::value(entry_kind!(&bin.item, kind, bin.tag.clone()))
+        }
+
+        let mut found_external = false;
+
+        if all {
+            if let Ok(locations) = ichwh::which_all(&bin.item).await {
+                for location in locations {
+                    found_external = true;
+                    yield ReturnSuccess::value(entry_path!(&bin.item, location.into(), bin.tag.clone()))
+                }
+            }
+        } else if let Ok(Some(location)) = ichwh::which(&bin.item).await {
+            found_external = true;
+            yield ReturnSuccess::value(entry_path!(&bin.item, location.into(), bin.tag.clone()))
+        }
+
+        if !found_external && !commands.has(&bin.item) && commands.get_alias(&bin.item).is_none() {
             yield Err(ShellError::labeled_error(
                 "Binary not found for argument, and argument is not a builtin",
                 "not found",